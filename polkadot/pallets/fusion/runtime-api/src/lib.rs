@@ -0,0 +1,45 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API for the Polkavex Fusion pallet
+//!
+//! Read-only queries wallets and relayers can run against chain state without
+//! submitting a transaction.
+
+use codec::Codec;
+use pallet_fusion::{AssetInfo, PalletSummary};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait FusionApi<AssetId, Balance, EscrowId, Escrow, AccountId>
+    where
+        AssetId: Codec,
+        Balance: Codec,
+        EscrowId: Codec,
+        Escrow: Codec,
+        AccountId: Codec,
+    {
+        /// What the pallet takes from the creator for creating an escrow of `amount` of `asset`
+        fn estimate_create_fee(asset: AssetInfo<AssetId>, amount: Balance) -> Balance;
+
+        /// Up to `limit` escrows following the `start_after` cursor, and the next cursor
+        fn escrows_paginated(
+            start_after: Option<EscrowId>,
+            limit: u32,
+        ) -> (Vec<(EscrowId, Escrow)>, Option<EscrowId>);
+
+        /// Escrows `taker` can complete right now
+        fn completable_escrows_for_taker(taker: AccountId) -> Vec<EscrowId>;
+
+        /// Account that holds `escrow_id`'s funds, for clients to fund or monitor
+        fn escrow_account_of(escrow_id: EscrowId) -> AccountId;
+
+        /// Up to `limit` of `creator`'s live escrows, skipping the first `offset`
+        fn account_escrows_paginated(creator: AccountId, offset: u32, limit: u32) -> Vec<EscrowId>;
+
+        /// Pause state, escrow counters, native TVL and the next escrow ID
+        fn pallet_summary() -> PalletSummary<EscrowId>;
+
+        /// Whether the escrow's funds are actually held, independent of its recorded state
+        fn is_fully_funded(escrow_id: EscrowId) -> bool;
+    }
+}
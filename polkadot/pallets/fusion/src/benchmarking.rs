@@ -1,171 +1,269 @@
-//! Benchmarking for pallet-fusion-escrow
+//! Benchmarking for pallet-fusion
 
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{benchmarks, whitelisted_caller, impl_benchmark_test_suite};
-use frame_support::{
-    traits::{tokens::Preservation, Get},
-    BoundedVec,
-};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller, BenchmarkError};
 use frame_system::RawOrigin;
-use sp_core::blake2_256;
+use sp_std::vec;
+use xcm::{
+    latest::{Junction, Junctions, MultiLocation},
+    VersionedMultiLocation,
+};
 
 const SEED: u32 = 0;
 
+/// Amount escrowed in each benchmark, clear of both the minimum and the existential deposit
+fn escrow_amount<T: Config>() -> T::Balance {
+    T::MinEscrowAmount::get().max(T::Currency::minimum_balance()).saturating_mul(10u32.into())
+}
+
+/// An account holding plenty of native balance
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    T::Currency::make_free_balance_be(&who, escrow_amount::<T>().saturating_mul(1_000u32.into()));
+    who
+}
+
+/// Route to a sibling parachain
+fn sibling_route() -> XcmRoute {
+    let destination = MultiLocation::new(1, Junctions::X1(Junction::Parachain(2000)));
+    XcmRoute {
+        destination: VersionedMultiLocation::from(destination).encode().try_into().expect("fits the bound; qed"),
+        route_data: Default::default(),
+    }
+}
+
+/// Create a native escrow from `creator` to `beneficiary`, returning its ID
+fn create_native<T: Config>(
+    creator: &T::AccountId,
+    beneficiary: &T::AccountId,
+    hashlock: Vec<u8>,
+    xcm_route: Option<XcmRoute>,
+) -> Result<T::EscrowId, BenchmarkError> {
+    let escrow_id = Pallet::<T>::next_escrow_id();
+    Pallet::<T>::create_escrow(
+        RawOrigin::Signed(creator.clone()).into(),
+        beneficiary.clone(),
+        AssetInfo::Native,
+        escrow_amount::<T>(),
+        hashlock,
+        T::MinTimelockDuration::get(),
+        Vec::new(),
+        xcm_route,
+    )?;
+    Ok(escrow_id)
+}
+
+/// Terms for a native template from the benchmark creator, with `m` bytes of metadata
+fn template_params<T: Config>(beneficiary: T::AccountId, m: u32) -> TemplateParamsOf<T> {
+    TemplateParams {
+        beneficiary,
+        asset: AssetInfo::Native,
+        timelock_duration: T::MinTimelockDuration::get(),
+        metadata: vec![0u8; m as usize],
+        xcm_route: None,
+    }
+}
+
+/// Create and fund a native escrow, accepting it first if the runtime requires that
+fn create_funded<T: Config>(
+    creator: &T::AccountId,
+    beneficiary: &T::AccountId,
+    hashlock: Vec<u8>,
+) -> Result<T::EscrowId, BenchmarkError> {
+    let escrow_id = create_native::<T>(creator, beneficiary, hashlock, None)?;
+    if T::RequireTakerAcceptance::get() {
+        Pallet::<T>::accept_escrow(RawOrigin::Signed(beneficiary.clone()).into(), escrow_id)?;
+    }
+    Pallet::<T>::fund_escrow(RawOrigin::Signed(creator.clone()).into(), escrow_id)?;
+    Ok(escrow_id)
+}
+
 benchmarks! {
     create_escrow {
-        let caller: T::AccountId = whitelisted_caller();
-        let taker: T::AccountId = whitelisted_caller();
-        let secret_hash = blake2_256(b"benchmark_secret");
-        let timelock = 1000u32.into();
-        let amount = 1000u128;
-        let metadata = BoundedVec::try_from(b"benchmark".to_vec()).unwrap();
-        
-        // Ensure caller has sufficient balance
-        T::Currency::mint_into(&caller, 10000u128)?;
+        let m in 0 .. T::MaxMetadataLen::get();
+        let caller = funded_account::<T>("creator", 0);
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let escrow_id = Pallet::<T>::next_escrow_id();
     }: _(
         RawOrigin::Signed(caller),
-        secret_hash,
-        timelock,
-        taker,
-        AssetType::Native,
-        amount,
-        None,
-        metadata
+        beneficiary,
+        AssetInfo::Native,
+        escrow_amount::<T>(),
+        vec![1u8; MAX_HASHLOCK_SIZE as usize],
+        T::MinTimelockDuration::get(),
+        vec![0u8; m as usize],
+        None
     )
     verify {
-        assert_eq!(Pallet::<T>::next_escrow_id(), 2);
-        assert!(Pallet::<T>::escrows(1).is_some());
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.state), Some(EscrowState::Created));
     }
 
     fund_escrow {
         let caller: T::AccountId = whitelisted_caller();
-        let taker: T::AccountId = whitelisted_caller();
-        let secret_hash = blake2_256(b"benchmark_secret");
-        let timelock = 1000u32.into();
-        let amount = 1000u128;
-        let metadata = BoundedVec::try_from(b"benchmark".to_vec()).unwrap();
-        
-        // Ensure caller has sufficient balance
-        T::Currency::mint_into(&caller, 10000u128)?;
-        
-        // Create escrow first
-        Pallet::<T>::create_escrow(
-            RawOrigin::Signed(caller.clone()).into(),
-            secret_hash,
-            timelock,
-            taker,
-            AssetType::Native,
-            amount,
-            None,
-            metadata
-        )?;
-    }: _(RawOrigin::Signed(caller), 1)
+        T::Currency::make_free_balance_be(&caller, escrow_amount::<T>().saturating_mul(1_000u32.into()));
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let escrow_id = create_native::<T>(&caller, &beneficiary, vec![1u8; MAX_HASHLOCK_SIZE as usize], None)?;
+        if T::RequireTakerAcceptance::get() {
+            Pallet::<T>::accept_escrow(RawOrigin::Signed(beneficiary).into(), escrow_id)?;
+        }
+    }: _(RawOrigin::Signed(caller), escrow_id)
     verify {
-        let escrow = Pallet::<T>::escrows(1).unwrap();
-        assert_eq!(escrow.state, EscrowState::Active);
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.state), Some(EscrowState::Active));
     }
 
     complete_escrow {
-        let caller: T::AccountId = whitelisted_caller();
-        let taker: T::AccountId = whitelisted_caller();
-        let secret = b"benchmark_secret_1234567890123456";
-        let secret_hash = blake2_256(secret);
-        let timelock = 1000u32.into();
-        let amount = 1000u128;
-        let metadata = BoundedVec::try_from(b"benchmark".to_vec()).unwrap();
-        
-        // Ensure caller has sufficient balance
-        T::Currency::mint_into(&caller, 10000u128)?;
-        
-        // Create and fund escrow
-        Pallet::<T>::create_escrow(
-            RawOrigin::Signed(caller.clone()).into(),
-            secret_hash,
-            timelock,
-            taker.clone(),
-            AssetType::Native,
-            amount,
-            None,
-            metadata
-        )?;
-        
-        Pallet::<T>::fund_escrow(
-            RawOrigin::Signed(caller).into(),
-            1
-        )?;
-    }: _(RawOrigin::Signed(taker), 1, *secret)
+        let s in 1 .. MAX_SECRET_SIZE;
+        let creator = funded_account::<T>("creator", 0);
+        let beneficiary: T::AccountId = whitelisted_caller();
+        let secret: BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>> =
+            vec![7u8; s as usize].try_into().expect("within bound; qed");
+        let hashlock = T::DefaultHashAlgorithm::get().hash(&secret).to_vec();
+        let escrow_id = create_funded::<T>(&creator, &beneficiary, hashlock)?;
+    }: _(RawOrigin::Signed(beneficiary), escrow_id, secret)
+    verify {
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.state), Some(EscrowState::Completed));
+    }
+
+    complete_escrow_xcm {
+        let s in 1 .. MAX_SECRET_SIZE;
+        let creator = funded_account::<T>("creator", 0);
+        let beneficiary: T::AccountId = whitelisted_caller();
+        let secret: BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>> =
+            vec![7u8; s as usize].try_into().expect("within bound; qed");
+        let hashlock = T::DefaultHashAlgorithm::get().hash(&secret).to_vec();
+        let escrow_id = create_native::<T>(&creator, &beneficiary, hashlock, Some(sibling_route()))?;
+        if T::RequireTakerAcceptance::get() {
+            Pallet::<T>::accept_escrow(RawOrigin::Signed(beneficiary.clone()).into(), escrow_id)?;
+        }
+        Pallet::<T>::fund_escrow(RawOrigin::Signed(creator).into(), escrow_id)?;
+    }: complete_escrow(RawOrigin::Signed(beneficiary), escrow_id, secret)
     verify {
-        let escrow = Pallet::<T>::escrows(1).unwrap();
-        assert_eq!(escrow.state, EscrowState::Completed);
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.state), Some(EscrowState::Completed));
     }
 
     cancel_escrow {
         let caller: T::AccountId = whitelisted_caller();
-        let taker: T::AccountId = whitelisted_caller();
-        let secret_hash = blake2_256(b"benchmark_secret");
-        let timelock = 10u32.into();
-        let amount = 1000u128;
-        let metadata = BoundedVec::try_from(b"benchmark".to_vec()).unwrap();
-        
-        // Ensure caller has sufficient balance
-        T::Currency::mint_into(&caller, 10000u128)?;
-        
-        // Create and fund escrow
-        Pallet::<T>::create_escrow(
-            RawOrigin::Signed(caller.clone()).into(),
-            secret_hash,
-            timelock,
-            taker,
-            AssetType::Native,
-            amount,
-            None,
-            metadata
-        )?;
-        
-        Pallet::<T>::fund_escrow(
+        T::Currency::make_free_balance_be(&caller, escrow_amount::<T>().saturating_mul(1_000u32.into()));
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let escrow_id = create_funded::<T>(&caller, &beneficiary, vec![1u8; MAX_HASHLOCK_SIZE as usize])?;
+    }: _(RawOrigin::Signed(caller), escrow_id, b"benchmark".to_vec().try_into().expect("within bound; qed"))
+    verify {
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.state), Some(EscrowState::Cancelled));
+    }
+
+    create_escrow_with_id {
+        let m in 0 .. T::MaxMetadataLen::get();
+        let caller = funded_account::<T>("creator", 0);
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let hashlock = vec![1u8; MAX_HASHLOCK_SIZE as usize];
+        let escrow_id = Pallet::<T>::derive_escrow_id(&hashlock, &caller);
+    }: _(
+        RawOrigin::Signed(caller),
+        beneficiary,
+        AssetInfo::Native,
+        escrow_amount::<T>(),
+        hashlock,
+        T::MinTimelockDuration::get(),
+        vec![0u8; m as usize],
+        None
+    )
+    verify {
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.state), Some(EscrowState::Created));
+    }
+
+    create_escrow_with_options {
+        let m in 0 .. T::MaxMetadataLen::get();
+        let caller = funded_account::<T>("creator", 0);
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let escrow_id = Pallet::<T>::next_escrow_id();
+    }: _(
+        RawOrigin::Signed(caller),
+        beneficiary,
+        AssetInfo::Native,
+        escrow_amount::<T>(),
+        vec![1u8; MAX_HASHLOCK_SIZE as usize],
+        T::MinTimelockDuration::get(),
+        vec![0u8; m as usize],
+        None,
+        Default::default()
+    )
+    verify {
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.state), Some(EscrowState::Created));
+    }
+
+    update_metadata {
+        let m in 0 .. T::MaxMetadataLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, escrow_amount::<T>().saturating_mul(1_000u32.into()));
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let escrow_id = create_native::<T>(&caller, &beneficiary, vec![1u8; MAX_HASHLOCK_SIZE as usize], None)?;
+    }: _(RawOrigin::Signed(caller), escrow_id, vec![0u8; m as usize])
+    verify {
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.metadata.len()), Some(m as usize));
+    }
+
+    register_template {
+        let m in 0 .. T::MaxMetadataLen::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let template_id = Pallet::<T>::next_template_id();
+    }: _(RawOrigin::Signed(caller), template_params::<T>(beneficiary, m))
+    verify {
+        assert!(Pallet::<T>::escrow_templates(template_id).is_some());
+    }
+
+    create_from_template {
+        let m in 0 .. T::MaxMetadataLen::get();
+        let caller = funded_account::<T>("creator", 0);
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let template_id = Pallet::<T>::next_template_id();
+        Pallet::<T>::register_template(
             RawOrigin::Signed(caller.clone()).into(),
-            1
+            template_params::<T>(beneficiary, m),
         )?;
-        
-        // Move past timelock
-        frame_system::Pallet::<T>::set_block_number(timelock + 1u32.into());
-    }: _(RawOrigin::Signed(caller), 1)
+        let escrow_id = Pallet::<T>::next_escrow_id();
+    }: _(RawOrigin::Signed(caller), template_id, vec![1u8; MAX_HASHLOCK_SIZE as usize], escrow_amount::<T>())
     verify {
-        let escrow = Pallet::<T>::escrows(1).unwrap();
-        assert_eq!(escrow.state, EscrowState::Cancelled);
+        assert_eq!(Pallet::<T>::escrows(&escrow_id).map(|escrow| escrow.metadata.len()), Some(m as usize));
     }
 
-    cancel_before_funding {
-        let caller: T::AccountId = whitelisted_caller();
-        let taker: T::AccountId = whitelisted_caller();
-        let secret_hash = blake2_256(b"benchmark_secret");
-        let timelock = 1000u32.into();
-        let amount = 1000u128;
-        let metadata = BoundedVec::try_from(b"benchmark".to_vec()).unwrap();
-        
-        // Create escrow (but don't fund)
+    renew_escrow {
+        let m in 0 .. T::MaxMetadataLen::get();
+        let caller = funded_account::<T>("creator", 0);
+        let beneficiary = funded_account::<T>("beneficiary", 0);
+        let escrow_id = Pallet::<T>::next_escrow_id();
         Pallet::<T>::create_escrow(
             RawOrigin::Signed(caller.clone()).into(),
-            secret_hash,
-            timelock,
-            taker,
-            AssetType::Native,
-            amount,
+            beneficiary,
+            AssetInfo::Native,
+            escrow_amount::<T>(),
+            vec![1u8; MAX_HASHLOCK_SIZE as usize],
+            T::MinTimelockDuration::get(),
+            vec![0u8; m as usize],
             None,
-            metadata
         )?;
-    }: _(RawOrigin::Signed(caller), 1)
+        Pallet::<T>::cancel_before_funding(RawOrigin::Signed(caller.clone()).into(), escrow_id)?;
+        let renewed_id = Pallet::<T>::next_escrow_id();
+    }: _(RawOrigin::Signed(caller), escrow_id, vec![2u8; MAX_HASHLOCK_SIZE as usize], T::MinTimelockDuration::get())
+    verify {
+        assert_eq!(Pallet::<T>::escrows(&renewed_id).map(|escrow| escrow.metadata.len()), Some(m as usize));
+    }
+
+    emergency_pause {
+        let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin)
     verify {
-        let escrow = Pallet::<T>::escrows(1).unwrap();
-        assert_eq!(escrow.state, EscrowState::Cancelled);
+        assert!(Pallet::<T>::emergency_paused());
     }
 
-    toggle_pause {
-    }: _(RawOrigin::Root)
+    emergency_unpause {
+        let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Paused::<T>::put(PauseFlags::emergency());
+    }: _<T::RuntimeOrigin>(origin)
     verify {
-        assert_eq!(Pallet::<T>::is_paused(), true);
+        assert!(!Pallet::<T>::emergency_paused());
     }
 
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
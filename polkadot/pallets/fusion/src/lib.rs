@@ -9,21 +9,34 @@
 //! secure cross-chain atomic swaps with DOT, parachain tokens, and future NFT support.
 
 use frame_support::{
-    codec::{Decode, Encode},
+    codec::{Decode, DecodeAll, Encode},
     dispatch::DispatchResult,
-    traits::{Get, Time, fungibles::Inspect, fungibles::Mutate, Randomness, tokens::Preservation, 
-             Currency, ReservableCurrency, ExistenceRequirement},
+    traits::{Get, Time, fungibles::Inspect, fungibles::Mutate, Randomness, tokens::{Fortitude, Precision, Preservation},
+             schedule::{v3::Named as ScheduleNamed, DispatchTime, LOWEST_PRIORITY},
+             BalanceStatus, Bounded, Currency, NamedReservableCurrency, ReservableCurrency, ExistenceRequirement},
     PalletId,
     pallet_prelude::*,
     storage::bounded_vec::BoundedVec,
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
-    traits::{AccountIdConversion, Saturating, Zero, CheckedAdd},
+    traits::{
+        AccountIdConversion, AtLeast32BitUnsigned, Convert, IdentifyAccount, Saturating, CheckedAdd, TrailingZeroInput,
+        One, Verify, Zero,
+    },
+    helpers_128bit::multiply_by_rational_with_rounding,
+    Rounding, SaturatedConversion, TokenError,
 };
 use sp_std::vec::Vec;
 use scale_info::TypeInfo;
 use sp_core::H256;
+use xcm::{
+    latest::{
+        ExecuteXcm, Instruction, InteriorMultiLocation, Junction, MultiAsset, MultiAssetFilter, MultiLocation,
+        WeightLimit, WildMultiAsset, Xcm, XcmHash,
+    },
+    VersionedMultiLocation,
+};
 
 pub use pallet::*;
 
@@ -31,6 +44,8 @@ pub use pallet::*;
 pub mod weights;
 pub use weights::WeightInfo;
 
+pub mod migrations;
+
 // Import test utilities when building for tests
 #[cfg(test)]
 mod mock;
@@ -47,8 +62,56 @@ pub const MAX_ESCROWS_PER_ACCOUNT: u32 = 1000;
 /// Maximum hashlock size (32 bytes for SHA256)
 pub const MAX_HASHLOCK_SIZE: u32 = 32;
 
-/// Maximum metadata size for escrow descriptions
-pub const MAX_METADATA_SIZE: u32 = 1024;
+/// Decimals of the native token (DOT)
+pub const NATIVE_DECIMALS: u8 = 10;
+
+/// Decimals of the canonical representation used to compare amounts across chains
+pub const CANONICAL_DECIMALS: u8 = 18;
+
+/// Number of escrow lifecycle events kept in the on-chain ring buffer
+pub const MAX_RECENT_EVENTS: u32 = 64;
+
+/// Maximum alternative payout accounts an escrow can pre-authorize
+pub const MAX_ALLOWED_RECIPIENTS: u32 = 4;
+
+/// Domain tag of the creator-signed message authorizing `mutual_cancel`
+pub const MUTUAL_CANCEL_CONTEXT: &[u8] = b"fusion/mutual-cancel";
+
+/// Domain tag of the creator-signed message authorizing `complete_escrow_with_auth`
+pub const COMPLETE_AUTH_CONTEXT: &[u8] = b"fusion/complete";
+
+/// Randomness subject for per-escrow account salts
+pub const ESCROW_SALT_CONTEXT: &[u8] = b"fusion/escrow-salt";
+
+/// Most times a secret may be hashed to reach its hashlock
+pub const MAX_HASH_ROUNDS: u8 = 8;
+
+/// Longest secret accepted for completion or reveal; longer ones fail to decode
+pub const MAX_SECRET_SIZE: u32 = 64;
+
+/// Longest secret kept in storage after completion
+pub const MAX_RETAINED_SECRET_SIZE: u32 = MAX_SECRET_SIZE;
+
+/// Maximum accounts an NFT escrow can restrict its payout to
+pub const MAX_NFT_CLAIMANTS: u32 = 4;
+
+/// Context hashed with the escrow ID to name its scheduled expiry notification
+pub const EXPIRY_TASK_CONTEXT: &[u8] = b"fusion/expiry";
+
+/// Smoothing divisor for `AvgCompletionBlocks`; higher values weigh history more
+pub const COMPLETION_EMA_WEIGHT: u64 = 8;
+
+/// Maximum length of an XCM route's encoded destination
+pub const MAX_XCM_DESTINATION_LEN: u32 = 256;
+
+/// Maximum number of signers behind a threshold maker
+pub const MAX_MAKER_SIGNERS: u32 = 8;
+
+/// Maximum length of the free-text reason given to `cancel_escrow`
+pub const MAX_CANCEL_REASON_LEN: u32 = 128;
+
+/// Set on every derived escrow ID, keeping them clear of the sequential counter
+pub const DERIVED_ESCROW_ID_FLAG: u64 = 1 << 63;
 
 /// Escrow state enumeration
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -61,8 +124,24 @@ pub enum EscrowState {
     Completed,
     /// Escrow cancelled or expired
     Cancelled,
-    /// Emergency pause state
+    /// Funded but held by `PauseOrigin`; neither completion nor cancellation may proceed
     Paused,
+    /// Expired and awaiting refund; the beneficiary may still dispute during the hold
+    Disputable,
+    /// Terms accepted by the beneficiary, not yet funded
+    Accepted,
+}
+
+impl EscrowState {
+    /// Whether the escrow account currently holds the escrowed funds
+    pub fn holds_funds(&self) -> bool {
+        matches!(self, Self::Active | Self::Paused | Self::Disputable)
+    }
+
+    /// Whether the escrow exists but has not been funded yet
+    pub fn awaiting_funding(&self) -> bool {
+        matches!(self, Self::Created | Self::Accepted)
+    }
 }
 
 impl Default for EscrowState {
@@ -71,6 +150,121 @@ impl Default for EscrowState {
     }
 }
 
+/// Why an escrow was cancelled, for indexers that shouldn't parse the free-text reason
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CancelReason {
+    /// Funded escrow refunded after its timelock passed
+    TimelockExpired,
+    /// Unfunded escrow abandoned by its creator
+    CancelledBeforeFunding,
+    /// Funded escrow withdrawn by its creator before the timelock
+    CancelledByCreator,
+    /// Cancellation imposed by a privileged origin
+    ForcedByGovernance,
+    /// Expired escrow refunded by someone other than the creator
+    RefundClaimedByThirdParty,
+    /// Beneficiary aborted early with the creator's signed consent
+    MutualAbort,
+}
+
+/// Hash function a hashlock was computed with
+///
+/// A hashlock of `MAX_HASHLOCK_SIZE + 1` bytes names its algorithm in the first byte:
+/// `0x01` SHA-256, `0x02` Keccak-256, `0x03` Blake2-256. The tag is stripped before the
+/// hashlock is stored. Untagged hashlocks use the runtime's `DefaultHashAlgorithm`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum HashAlgorithm {
+    Sha256,
+    Keccak256,
+    Blake2_256,
+}
+
+impl Default for HashAlgorithm {
+    fn default() -> Self {
+        Self::Sha256
+    }
+}
+
+impl HashAlgorithm {
+    /// Every supported algorithm
+    pub const ALL: [HashAlgorithm; 3] = [Self::Sha256, Self::Keccak256, Self::Blake2_256];
+
+    /// Algorithm named by a hashlock tag byte
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0x01 => Some(Self::Sha256),
+            0x02 => Some(Self::Keccak256),
+            0x03 => Some(Self::Blake2_256),
+            _ => None,
+        }
+    }
+
+    /// Tag byte naming this algorithm
+    pub fn tag(&self) -> u8 {
+        match self {
+            Self::Sha256 => 0x01,
+            Self::Keccak256 => 0x02,
+            Self::Blake2_256 => 0x03,
+        }
+    }
+
+    /// Hash a secret
+    pub fn hash(&self, data: &[u8]) -> [u8; 32] {
+        match self {
+            Self::Sha256 => sp_core::hashing::sha2_256(data),
+            Self::Keccak256 => sp_core::hashing::keccak_256(data),
+            Self::Blake2_256 => sp_core::hashing::blake2_256(data),
+        }
+    }
+
+    /// Hash a secret `rounds` times, feeding each digest into the next round
+    pub fn hash_rounds(&self, data: &[u8], rounds: u8) -> [u8; 32] {
+        let mut digest = self.hash(data);
+        for _ in 1..rounds {
+            digest = self.hash(&digest);
+        }
+        digest
+    }
+}
+
+/// Kind of lifecycle step recorded in the recent events buffer
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum EscrowEventKind {
+    Created,
+    Funded,
+    Completed,
+    Cancelled,
+}
+
+/// Compact record of an escrow lifecycle step for light clients
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EscrowEventSummary<EscrowId, BlockNumber> {
+    pub escrow_id: EscrowId,
+    pub kind: EscrowEventKind,
+    pub block: BlockNumber,
+}
+
+/// Client-facing status of an escrow, derived from its state and the current block
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum EscrowStatus<BlockNumber> {
+    /// No escrow with this ID exists
+    NotFound,
+    /// Created but not yet funded
+    AwaitingFunding,
+    /// Funded and the secret can still be revealed
+    ActiveCompletable { remaining: BlockNumber },
+    /// Funded but the timelock has passed; only a refund is possible
+    ActiveExpired,
+    /// Completed successfully
+    Completed,
+    /// Cancelled or refunded
+    Cancelled,
+    /// Held by an emergency pause
+    Paused,
+    /// Refund pending; it can be finalized from `finalize_at` unless disputed
+    Disputable { finalize_at: BlockNumber },
+}
+
 /// Asset information for multi-asset support
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum AssetInfo<AssetId> {
@@ -82,6 +276,8 @@ pub enum AssetInfo<AssetId> {
     Stablecoin { asset_id: AssetId, decimals: u8, symbol: BoundedVec<u8, ConstU32<32>> },
     /// NFT support for Day 5 enhancements
     Nft { collection_id: AssetId, item_id: u32, metadata: BoundedVec<u8, ConstU32<256>> },
+    /// Bridged wrapped asset, moved by burning from the sender and minting to the receiver
+    Bridged(AssetId),
 }
 
 /// Asset type classification for routing optimization
@@ -93,27 +289,272 @@ pub enum AssetType {
     Nft,
 }
 
+/// Identity of an asset for per-asset settings, without per-escrow details
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AssetKey<AssetId> {
+    /// Native DOT token
+    Native,
+    /// `pallet_assets` asset, covering both plain fungibles and stablecoins
+    Asset(AssetId),
+    /// Every item of an NFT collection
+    NftCollection(AssetId),
+}
+
+impl<AssetId: Copy> AssetInfo<AssetId> {
+    /// Key identifying the underlying asset
+    pub fn key(&self) -> AssetKey<AssetId> {
+        match self {
+            AssetInfo::Native => AssetKey::Native,
+            AssetInfo::Asset(asset_id) | AssetInfo::Stablecoin { asset_id, .. } | AssetInfo::Bridged(asset_id) =>
+                AssetKey::Asset(*asset_id),
+            AssetInfo::Nft { collection_id, .. } => AssetKey::NftCollection(*collection_id),
+        }
+    }
+}
+
+impl<AssetId: Copy> AssetKey<AssetId> {
+    /// Plain fungible form of this asset, `None` for NFT collections
+    pub fn as_fungible(&self) -> Option<AssetInfo<AssetId>> {
+        match self {
+            AssetKey::Native => Some(AssetInfo::Native),
+            AssetKey::Asset(asset_id) => Some(AssetInfo::Asset(*asset_id)),
+            AssetKey::NftCollection(_) => None,
+        }
+    }
+}
+
 impl<AssetId> AssetInfo<AssetId> {
     /// Get the asset type for routing decisions
     pub fn asset_type(&self) -> AssetType {
         match self {
             AssetInfo::Native => AssetType::Native,
-            AssetInfo::Asset(_) => AssetType::Fungible,
+            AssetInfo::Asset(_) | AssetInfo::Bridged(_) => AssetType::Fungible,
             AssetInfo::Stablecoin { .. } => AssetType::Stablecoin,
             AssetInfo::Nft { .. } => AssetType::Nft,
         }
     }
+
+    /// Decimals of the asset, if known
+    pub fn decimals(&self) -> Option<u8> {
+        match self {
+            AssetInfo::Native => Some(NATIVE_DECIMALS),
+            AssetInfo::Stablecoin { decimals, .. } => Some(*decimals),
+            AssetInfo::Asset(_) | AssetInfo::Nft { .. } | AssetInfo::Bridged(_) => None,
+        }
+    }
 }
 
-/// XCM routing information for cross-chain operations
+/// Pallet-wide health snapshot for monitoring, read from counters without iteration
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PalletSummary<EscrowId> {
+    /// Whether any operation is paused
+    pub paused: bool,
+    pub total_active: u64,
+    pub total_completed: u64,
+    /// Native value locked across active escrows
+    pub tvl_native: u128,
+    /// ID the next sequential escrow will take
+    pub next_id: EscrowId,
+}
+
+/// Escrowed total of one asset against what its escrow accounts actually hold
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetSolvency<AssetId> {
+    pub asset: AssetKey<AssetId>,
+    /// Sum of `amount` over active escrows
+    pub expected: u128,
+    /// Sum of the balances held by those escrows' accounts
+    pub actual: u128,
+}
+
+impl<AssetId> AssetSolvency<AssetId> {
+    /// Whether the escrow accounts cover everything owed
+    pub fn is_solvent(&self) -> bool {
+        self.actual >= self.expected
+    }
+}
+
+/// Decides whether transfers of an asset out of a user's account may reap it
+pub trait PreservationPolicy<AssetId> {
+    /// Preservation to apply when moving `asset` out of a user's account
+    fn preservation_for(asset: &AssetInfo<AssetId>) -> Preservation;
+}
+
+/// Let every transfer reap the sender
+impl<AssetId> PreservationPolicy<AssetId> for () {
+    fn preservation_for(_asset: &AssetInfo<AssetId>) -> Preservation {
+        Preservation::Expendable
+    }
+}
+
+/// Lets other pallets react as escrows move through their lifecycle
+pub trait EscrowEventHandler<EscrowId, Escrow> {
+    /// Escrow was funded and its assets are now locked
+    fn on_funded(escrow_id: &EscrowId, escrow: &Escrow);
+    /// Escrow paid out to its beneficiary
+    fn on_completed(escrow_id: &EscrowId, escrow: &Escrow);
+    /// Escrow was cancelled or refunded
+    fn on_cancelled(escrow_id: &EscrowId, escrow: &Escrow);
+}
+
+impl<EscrowId, Escrow> EscrowEventHandler<EscrowId, Escrow> for () {
+    fn on_funded(_escrow_id: &EscrowId, _escrow: &Escrow) {}
+    fn on_completed(_escrow_id: &EscrowId, _escrow: &Escrow) {}
+    fn on_cancelled(_escrow_id: &EscrowId, _escrow: &Escrow) {}
+}
+
+/// XCM routing information for cross-chain operations
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct XcmRoute {
-    /// Destination parachain
-    pub destination: sp_std::vec::Vec<u8>, // Simplified for now
+    /// SCALE-encoded `VersionedMultiLocation` of the destination chain
+    pub destination: BoundedVec<u8, ConstU32<MAX_XCM_DESTINATION_LEN>>,
     /// Additional routing data
     pub route_data: BoundedVec<u8, ConstU32<256>>,
 }
 
+/// Operations that can be paused independently
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PauseFlags {
+    /// Block `create_escrow` and its variants
+    pub create: bool,
+    /// Block `fund_escrow`
+    pub fund: bool,
+    /// Block `complete_escrow`
+    pub complete: bool,
+    /// Block cancellations and refunds
+    pub cancel: bool,
+}
+
+impl PauseFlags {
+    /// Incident preset: stop new escrows but let existing ones complete or refund
+    pub fn emergency() -> Self {
+        Self { create: true, fund: true, complete: false, cancel: false }
+    }
+
+    /// Whether any operation is paused
+    pub fn any(&self) -> bool {
+        self.create || self.fund || self.complete || self.cancel
+    }
+}
+
+/// Quotes exchange rates between assets for cross-asset payouts
+pub trait PriceProvider<AssetId, Balance> {
+    /// Value of `amount` of `from` denominated in `to`, `None` if no rate is available
+    fn convert(from: &AssetKey<AssetId>, to: &AssetKey<AssetId>, amount: Balance) -> Option<Balance>;
+}
+
+/// No oracle: only same-asset payouts are possible
+impl<AssetId, Balance> PriceProvider<AssetId, Balance> for () {
+    fn convert(_from: &AssetKey<AssetId>, _to: &AssetKey<AssetId>, _amount: Balance) -> Option<Balance> {
+        None
+    }
+}
+
+/// Flags maker and taker pairs believed to be the same party, such as linked proxy accounts
+pub trait SelfDealFilter<AccountId> {
+    /// Whether an escrow from `maker` to `taker` should be rejected as self-dealing
+    fn is_related(maker: &AccountId, taker: &AccountId) -> bool;
+}
+
+/// Treat every pair as unrelated
+impl<AccountId> SelfDealFilter<AccountId> for () {
+    fn is_related(_maker: &AccountId, _taker: &AccountId) -> bool {
+        false
+    }
+}
+
+/// Reports whether an escrow's secret has already been revealed on the counterparty chain,
+/// e.g. from a light client or bridge proof
+pub trait RevealVerifier<EscrowId> {
+    /// Whether the secret behind `hashlock` was revealed elsewhere for `escrow_id`
+    fn revealed_elsewhere(escrow_id: &EscrowId, hashlock: &[u8]) -> bool;
+}
+
+/// Never reports a reveal, so cancellation is never blocked
+impl<EscrowId> RevealVerifier<EscrowId> for () {
+    fn revealed_elsewhere(_escrow_id: &EscrowId, _hashlock: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Optional terms fixed when an escrow is created
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EscrowOptions<AccountId, Balance, AssetId, BlockNumber> {
+    /// Native bond the beneficiary must post before funding, forfeited to the creator
+    /// if the escrow expires unredeemed
+    pub bond: Option<Balance>,
+    /// Unix deadline in seconds, enforced alongside the block timelock so the escrow can
+    /// expire in step with a timestamp-locked Ethereum leg
+    pub timelock_timestamp: Option<u64>,
+    /// Accounts besides the beneficiary that `complete_escrow_to` may pay out to
+    pub allowed_recipients: BoundedVec<AccountId, ConstU32<MAX_ALLOWED_RECIPIENTS>>,
+    /// For NFT escrows, the only accounts the item may be paid out to; empty means any
+    pub nft_claimants: BoundedVec<AccountId, ConstU32<MAX_NFT_CLAIMANTS>>,
+    /// Asset the beneficiary is paid in, converted from the locked asset at the oracle rate;
+    /// `None` pays out the locked asset itself
+    pub payout_asset: Option<AssetKey<AssetId>>,
+    /// Blocks an expired escrow is held before its refund can be finalized, giving the
+    /// beneficiary time to dispute; `None` refunds immediately
+    pub dispute_window: Option<BlockNumber>,
+    /// Times the secret is hashed to reach the hashlock, e.g. 2 for `hash(hash(secret))`
+    pub hash_rounds: u8,
+    /// Block from which the creator may also complete, paying the beneficiary
+    pub maker_complete_after: Option<BlockNumber>,
+    /// Lock native funds as a named reserve on the funder's account instead of moving
+    /// them to the escrow account
+    pub reserve_native: bool,
+    /// `(start, end)` blocks over which the payout is released linearly after completion,
+    /// claimed with `claim_vested`; `None` pays out at once
+    pub vesting: Option<(BlockNumber, BlockNumber)>,
+    /// Paid to whoever submits the completion, funded on top of the escrowed amount and
+    /// refunded to the creator on cancellation
+    pub relayer_reward: Option<Balance>,
+    /// Hash of the Ethereum order this escrow settles, echoed on completion and used as the
+    /// claim payload's `orderHash`
+    pub eth_order_hash: Option<[u8; 32]>,
+    /// `(threshold, signers)` standing behind the creator: cancelling before the refund window
+    /// opens takes `threshold` of the signers' `approve_cancel` calls
+    pub maker_threshold: Option<(u16, BoundedVec<AccountId, ConstU32<MAX_MAKER_SIGNERS>>)>,
+    /// Paid instead when a local payout to the recipient fails, e.g. because the recipient
+    /// was reaped or its asset account frozen
+    pub fallback_taker: Option<AccountId>,
+    /// Client-chosen key making creation idempotent; each creator can use a nonce once
+    pub nonce: Option<u64>,
+}
+
+impl<AccountId, Balance, AssetId, BlockNumber> Default for EscrowOptions<AccountId, Balance, AssetId, BlockNumber> {
+    fn default() -> Self {
+        Self {
+            bond: None,
+            timelock_timestamp: None,
+            allowed_recipients: BoundedVec::default(),
+            nft_claimants: BoundedVec::default(),
+            payout_asset: None,
+            dispute_window: None,
+            hash_rounds: 1,
+            maker_complete_after: None,
+            reserve_native: false,
+            vesting: None,
+            relayer_reward: None,
+            eth_order_hash: None,
+            maker_threshold: None,
+            fallback_taker: None,
+            nonce: None,
+        }
+    }
+}
+
+/// Reusable escrow terms, so repeat escrows only need a hashlock and amount
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TemplateParams<AccountId, AssetId, BlockNumber> {
+    pub beneficiary: AccountId,
+    pub asset: AssetInfo<AssetId>,
+    pub timelock_duration: BlockNumber,
+    /// Checked against `MaxMetadataLen` on registration
+    pub metadata: Vec<u8>,
+    pub xcm_route: Option<XcmRoute>,
+}
+
 /// Core escrow structure
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -128,27 +569,92 @@ pub struct Escrow<T: Config> {
     pub asset: AssetInfo<T::AssetId>,
     /// Amount being escrowed
     pub amount: T::Balance,
+    /// Account that supplied the funds, once funded
+    pub funder: Option<T::AccountId>,
+    /// Block when the escrow was funded
+    pub funded_at: Option<BlockNumberFor<T>>,
     /// Hash lock for atomic swaps
     pub hashlock: BoundedVec<u8, ConstU32<MAX_HASHLOCK_SIZE>>,
+    /// Hash function the hashlock was computed with
+    pub hash_algorithm: HashAlgorithm,
     /// Time lock expiration
     pub timelock: BlockNumberFor<T>,
     /// Current state of the escrow
     pub state: EscrowState,
     /// Optional metadata
-    pub metadata: BoundedVec<u8, ConstU32<MAX_METADATA_SIZE>>,
+    pub metadata: BoundedVec<u8, T::MaxMetadataLen>,
     /// XCM routing for cross-chain operations
     pub xcm_route: Option<XcmRoute>,
     /// Block when escrow was created
     pub created_at: BlockNumberFor<T>,
     /// Block when escrow was last updated
     pub updated_at: BlockNumberFor<T>,
+    /// Optional terms set at creation
+    pub options: EscrowOptionsOf<T>,
+    /// Whether the beneficiary has posted the required bond
+    pub bond_posted: bool,
+    /// Held by an operator; completion and cancellation are blocked
+    pub frozen: bool,
+}
+
+/// Payout of a completed escrow released linearly to its recipient
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingSchedule<AccountId, Balance, BlockNumber> {
+    pub recipient: AccountId,
+    /// Payout being released, after the protocol fee
+    pub total: Balance,
+    /// Portion already paid out
+    pub claimed: Balance,
+    pub start: BlockNumber,
+    pub end: BlockNumber,
+}
+
+impl<AccountId, Balance: AtLeast32BitUnsigned + Copy, BlockNumber: AtLeast32BitUnsigned + Copy>
+    VestingSchedule<AccountId, Balance, BlockNumber>
+{
+    /// Portion of `total` released by block `now`
+    pub fn vested_at(&self, now: BlockNumber) -> Balance {
+        if now <= self.start {
+            return Zero::zero()
+        }
+        if now >= self.end {
+            return self.total
+        }
+        let elapsed: u128 = (now - self.start).saturated_into();
+        let duration: u128 = (self.end - self.start).saturated_into();
+        multiply_by_rational_with_rounding(self.total.saturated_into(), elapsed, duration, Rounding::Down)
+            .map_or(self.total, |vested| vested.saturated_into())
+    }
 }
 
+/// Vesting schedule for a given runtime
+pub type VestingScheduleOf<T> =
+    VestingSchedule<<T as frame_system::Config>::AccountId, <T as Config>::Balance, BlockNumberFor<T>>;
+
+/// Event summary for a given runtime
+pub type EscrowEventSummaryOf<T> = EscrowEventSummary<<T as Config>::EscrowId, BlockNumberFor<T>>;
+
+/// Creation options for a given runtime
+pub type EscrowOptionsOf<T> = EscrowOptions<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::Balance,
+    <T as Config>::AssetId,
+    BlockNumberFor<T>,
+>;
+
+/// Template terms for a given runtime
+pub type TemplateParamsOf<T> =
+    TemplateParams<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -168,14 +674,16 @@ pub mod pallet {
         type AssetId: Parameter + Copy + Default + MaxEncodedLen;
 
         /// Type for balances
-        type Balance: Parameter + Copy + Default + MaxEncodedLen + Zero + Saturating + CheckedAdd;
+        type Balance: Parameter + Copy + Default + MaxEncodedLen + AtLeast32BitUnsigned;
 
         /// Multi-asset support
         type Assets: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
             + Mutate<Self::AccountId>;
 
         /// Native currency operations using the traditional Currency trait
-        type Currency: Currency<Self::AccountId, Balance = Self::Balance> + ReservableCurrency<Self::AccountId>;
+        type Currency: Currency<Self::AccountId, Balance = Self::Balance>
+            + ReservableCurrency<Self::AccountId>
+            + NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
 
         /// Time provider for timelock functionality  
         type TimeProvider: Time<Moment = BlockNumberFor<Self>>;
@@ -183,6 +691,23 @@ pub mod pallet {
         /// Randomness source for generating secure escrow IDs
         type Randomness: Randomness<H256, BlockNumberFor<Self>>;
 
+        /// XCM executor used to route completed escrows to their destination chain
+        type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Converts local accounts into XCM locations
+        type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+
+        /// Converts asset IDs into XCM locations, `None` if the asset can't be routed
+        type AssetIdToMultiLocation: Convert<Self::AssetId, Option<MultiLocation>>;
+
+        /// This chain's location in the global consensus system, used to reanchor fees
+        #[pallet::constant]
+        type UniversalLocation: Get<InteriorMultiLocation>;
+
+        /// Weight limit for executing the XCM routing message locally
+        #[pallet::constant]
+        type XcmWeightLimit: Get<Weight>;
+
         /// Pallet ID for generating account addresses
         #[pallet::constant]
         type PalletId: Get<PalletId>;
@@ -202,6 +727,113 @@ pub mod pallet {
         /// Fee for creating an escrow
         #[pallet::constant]
         type EscrowFee: Get<Self::Balance>;
+
+        /// Minimum amount for fungible escrows (NFT escrows are exempt)
+        #[pallet::constant]
+        type MinEscrowAmount: Get<Self::Balance>;
+
+        /// Maximum number of escrow IDs returned by state enumeration helpers
+        #[pallet::constant]
+        type MaxEnumerated: Get<u32>;
+
+        /// Whether completions also emit an Ethereum ABI-encoded claim payload for relayers
+        #[pallet::constant]
+        type EmitEthClaimPayload: Get<bool>;
+
+        /// Signature a creator uses to authorize completion off-chain
+        type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+        /// Public key type behind `OffchainSignature`, identifying a local account
+        type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Origin allowed to freeze individual escrows
+        type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to pause and resume pallet operations
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// How funding transfers treat the funder's existential deposit, per asset
+        type PreservationPolicy: PreservationPolicy<Self::AssetId>;
+
+        /// Maximum escrows a single `reveal_secret` completes
+        #[pallet::constant]
+        type MaxLinkedReveals: Get<u32>;
+
+        /// Maximum length of escrow metadata, in bytes
+        #[pallet::constant]
+        type MaxMetadataLen: Get<u32>;
+
+        /// Maximum escrow IDs accepted by one `sweep_expired` call
+        #[pallet::constant]
+        type MaxSweep: Get<u32>;
+
+        /// Whether every asset is accepted while `AllowedAssets` is empty
+        #[pallet::constant]
+        type AllowAllWhenUnlisted: Get<bool>;
+
+        /// Blocks after the timelock before a funded escrow may be refunded, so the claim and
+        /// refund windows never meet
+        #[pallet::constant]
+        type CancelSafetyGap: Get<BlockNumberFor<Self>>;
+
+        /// Seconds after a Unix deadline before a funded escrow may be refunded
+        #[pallet::constant]
+        type CancelSafetyGapSecs: Get<u64>;
+
+        /// Maximum escrows settled by one `complete_escrow_batch` call
+        #[pallet::constant]
+        type MaxBatchCompletions: Get<u32>;
+
+        /// Notified when escrows are funded, completed or cancelled
+        type OnEscrowEvent: EscrowEventHandler<Self::EscrowId, Escrow<Self>>;
+
+        /// Share of each completed payout taken as protocol fee, in basis points
+        #[pallet::constant]
+        type ProtocolFeeBps: Get<u32>;
+
+        /// Account receiving protocol fees
+        type FeeCollector: Get<Self::AccountId>;
+
+        /// Origin allowed to withdraw accumulated protocol fees
+        type FeeAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Exchange rates for escrows paying out in a different asset than they lock
+        type PriceOracle: PriceProvider<Self::AssetId, Self::Balance>;
+
+        /// Rejects creator and beneficiary pairs flagged as the same party
+        type AntiSelfDeal: SelfDealFilter<Self::AccountId>;
+
+        /// Liquidity account that takes the locked asset and pays out the converted one
+        type ConversionPool: Get<Self::AccountId>;
+
+        /// Maximum escrows funded at once across all accounts
+        #[pallet::constant]
+        type MaxGlobalActiveEscrows: Get<u32>;
+
+        /// Hash function for untagged hashlocks, fixed per runtime to match its bridge
+        #[pallet::constant]
+        type DefaultHashAlgorithm: Get<HashAlgorithm>;
+
+        /// Blocks a completed or cancelled escrow is kept before idle-time pruning
+        #[pallet::constant]
+        type RetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Whether escrows must be accepted by their beneficiary before they can be funded
+        #[pallet::constant]
+        type RequireTakerAcceptance: Get<bool>;
+
+        /// Consulted before cancelling a funded escrow; a reveal on the counterparty chain
+        /// blocks the refund so the maker can't take both legs
+        type CrossChainProof: RevealVerifier<Self::EscrowId>;
+
+        /// The overarching call type, for scheduling this pallet's own calls
+        type RuntimeCall: From<Call<Self>> + IsType<<Self as frame_system::Config>::RuntimeCall> + Encode;
+
+        /// Origin type scheduled calls dispatch with
+        type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+        /// Schedules the expiry notification of funded escrows
+        type Scheduler: ScheduleNamed<BlockNumberFor<Self>, <Self as Config>::RuntimeCall, Self::PalletsOrigin>;
     }
 
     /// Storage for all escrows
@@ -226,109 +858,1962 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Escrows where an account is the beneficiary
+    #[pallet::storage]
+    #[pallet::getter(fn beneficiary_escrows)]
+    pub type BeneficiaryEscrows<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::EscrowId, T::MaxEscrowsPerAccount>,
+        ValueQuery,
+    >;
+
+    /// Index of escrow IDs by their current state (for efficient enumeration)
+    #[pallet::storage]
+    pub type EscrowsByState<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        EscrowState,
+        Blake2_128Concat,
+        T::EscrowId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Live escrows by hashlock, used to spot resubmissions and linked escrows
+    #[pallet::storage]
+    pub type EscrowsByHashlock<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, ConstU32<MAX_HASHLOCK_SIZE>>,
+        Blake2_128Concat,
+        T::EscrowId,
+        (),
+        OptionQuery,
+    >;
+
     /// Next available escrow ID
     #[pallet::storage]
     #[pallet::getter(fn next_escrow_id)]
     pub type NextEscrowId<T: Config> = StorageValue<_, T::EscrowId, ValueQuery>;
 
-    /// Emergency pause flag
+    /// Total amount currently held in active escrows, per asset type
     #[pallet::storage]
-    #[pallet::getter(fn emergency_paused)]
-    pub type EmergencyPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+    #[pallet::getter(fn total_locked)]
+    pub type TotalValueLocked<T: Config> = StorageMap<_, Blake2_128Concat, AssetType, u128, ValueQuery>;
 
-    /// Events emitted by the pallet
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// Escrow created successfully
-        EscrowCreated {
-            escrow_id: T::EscrowId,
-            creator: T::AccountId,
-            beneficiary: T::AccountId,
-            asset: AssetInfo<T::AssetId>,
-            amount: T::Balance,
-            timelock: BlockNumberFor<T>,
-        },
+    /// DOT-equivalent value of active escrows, priced through `PriceOracle` at funding
+    ///
+    /// Best-effort: escrows the oracle can't price are left out.
+    #[pallet::storage]
+    #[pallet::getter(fn tvl_in_dot)]
+    pub type TvlInDot<T: Config> = StorageValue<_, u128, ValueQuery>;
 
-        /// Escrow funded and activated
-        EscrowFunded {
-            escrow_id: T::EscrowId,
-            funder: T::AccountId,
-        },
+    /// What each active escrow added to `TvlInDot`, so the same amount comes off on settlement
+    #[pallet::storage]
+    pub type EscrowDotValue<T: Config> = StorageMap<_, Blake2_128Concat, T::EscrowId, u128>;
 
-        /// Escrow completed successfully
-        EscrowCompleted {
-            escrow_id: T::EscrowId,
-            beneficiary: T::AccountId,
-            secret: Vec<u8>,
-        },
+    /// Per-asset-type `(min, max)` timelock durations overriding the global bounds
+    #[pallet::storage]
+    #[pallet::getter(fn asset_timelock_bounds)]
+    pub type AssetTimelockBounds<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetType, (BlockNumberFor<T>, BlockNumberFor<T>)>;
 
-        /// Escrow cancelled or expired
-        EscrowCancelled {
-            escrow_id: T::EscrowId,
-            canceller: T::AccountId,
-            reason: Vec<u8>,
-        },
+    /// Escrows ever created
+    #[pallet::storage]
+    #[pallet::getter(fn total_created)]
+    pub type TotalCreated<T: Config> = StorageValue<_, u64, ValueQuery>;
 
-        /// Emergency pause activated
-        EmergencyPauseActivated {
+    /// Escrows currently funded and awaiting completion or refund
+    #[pallet::storage]
+    #[pallet::getter(fn total_active)]
+    pub type TotalActive<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Escrows ever completed
+    #[pallet::storage]
+    #[pallet::getter(fn total_completed)]
+    pub type TotalCompleted<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Exponential moving average of blocks from funding to completion, once one completes
+    #[pallet::storage]
+    #[pallet::getter(fn avg_completion_blocks)]
+    pub type AvgCompletionBlocks<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Last escrow visited by idle-time pruning; the next scan resumes after it
+    #[pallet::storage]
+    pub type PruneCursor<T: Config> = StorageValue<_, T::EscrowId, OptionQuery>;
+
+    /// Escrows ever cancelled or refunded
+    #[pallet::storage]
+    #[pallet::getter(fn total_cancelled)]
+    pub type TotalCancelled<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Random salt mixed into each escrow's account; escrows without one use the legacy derivation
+    #[pallet::storage]
+    #[pallet::getter(fn escrow_salts)]
+    pub type EscrowSalts<T: Config> = StorageMap<_, Blake2_128Concat, T::EscrowId, H256>;
+
+    /// Whether revealed secrets are kept in `RevealedSecrets` after completion
+    #[pallet::storage]
+    #[pallet::getter(fn retain_revealed_secrets)]
+    pub type RetainRevealedSecrets<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Secrets of completed escrows, kept while retention is on
+    #[pallet::storage]
+    pub type RevealedSecrets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::EscrowId, BoundedVec<u8, ConstU32<MAX_RETAINED_SECRET_SIZE>>>;
+
+    /// Escrow templates with their owning account
+    #[pallet::storage]
+    #[pallet::getter(fn escrow_templates)]
+    pub type EscrowTemplates<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, (T::AccountId, TemplateParamsOf<T>)>;
+
+    /// Next available template ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_template_id)]
+    pub type NextTemplateId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Protocol fees charged per asset and not yet withdrawn from the fee collector
+    #[pallet::storage]
+    #[pallet::getter(fn accumulated_fees)]
+    pub type AccumulatedFees<T: Config> = StorageMap<_, Blake2_128Concat, AssetKey<T::AssetId>, u128, ValueQuery>;
+
+    /// Block from which each `Disputable` escrow's refund may be finalized
+    #[pallet::storage]
+    #[pallet::getter(fn refund_holds)]
+    pub type RefundHolds<T: Config> = StorageMap<_, Blake2_128Concat, T::EscrowId, BlockNumberFor<T>>;
+
+    /// Operations currently paused
+    #[pallet::storage]
+    #[pallet::getter(fn pause_flags)]
+    pub type Paused<T: Config> = StorageValue<_, PauseFlags, ValueQuery>;
+
+    /// Most recent escrow lifecycle events, oldest first
+    #[pallet::storage]
+    pub type RecentEvents<T: Config> =
+        StorageValue<_, BoundedVec<EscrowEventSummaryOf<T>, ConstU32<MAX_RECENT_EVENTS>>, ValueQuery>;
+
+    /// Assets escrows may be created in, once any are listed
+    #[pallet::storage]
+    #[pallet::getter(fn allowed_assets)]
+    pub type AllowedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetKey<T::AssetId>, bool, ValueQuery>;
+
+    /// Number of entries in `AllowedAssets`
+    #[pallet::storage]
+    pub type AllowedAssetCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Amount of an asset an agent may still pull from a maker to fund the maker's escrows
+    #[pallet::storage]
+    #[pallet::getter(fn funding_approvals)]
+    pub type FundingApprovals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (T::AccountId, AssetKey<T::AssetId>),
+        T::Balance,
+        ValueQuery,
+    >;
+
+    /// Vesting payouts of completed escrows that have not been fully claimed
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedules)]
+    pub type VestingSchedules<T: Config> = StorageMap<_, Blake2_128Concat, T::EscrowId, VestingScheduleOf<T>>;
+
+    /// Creation nonces each creator has used
+    #[pallet::storage]
+    pub type UsedNonces<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, u64, (), OptionQuery>;
+
+    /// Signers that have approved cancelling a threshold maker's escrow
+    #[pallet::storage]
+    #[pallet::getter(fn cancel_approvals)]
+    pub type CancelApprovals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::EscrowId,
+        BoundedVec<T::AccountId, ConstU32<MAX_MAKER_SIGNERS>>,
+        ValueQuery,
+    >;
+
+    /// Events emitted by the pallet
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Escrow created successfully
+        EscrowCreated {
+            escrow_id: T::EscrowId,
+            creator: T::AccountId,
+            beneficiary: T::AccountId,
+            asset: AssetInfo<T::AssetId>,
+            amount: T::Balance,
+            /// `amount` scaled to `CANONICAL_DECIMALS`
+            normalized_amount: u128,
+            timelock: BlockNumberFor<T>,
+            /// Account that must receive the escrowed funds
+            escrow_account: T::AccountId,
+            /// Block the transition happened in
+            at_block: BlockNumberFor<T>,
+            /// Unix time of that block, in seconds
+            at_time: u64,
+        },
+
+        /// Escrow funded and activated
+        EscrowFunded {
+            escrow_id: T::EscrowId,
+            funder: T::AccountId,
+            at_block: BlockNumberFor<T>,
+            at_time: u64,
+        },
+
+        /// Amount of an unfunded escrow changed
+        EscrowAmountUpdated {
+            escrow_id: T::EscrowId,
+            old: T::Balance,
+            new: T::Balance,
+        },
+
+        /// Beneficiary of an unfunded escrow changed
+        BeneficiaryReassigned {
+            escrow_id: T::EscrowId,
+            old: T::AccountId,
+            new: T::AccountId,
+        },
+
+        /// Beneficiary reserved the bond required by the escrow
+        BondPosted {
+            escrow_id: T::EscrowId,
+            beneficiary: T::AccountId,
+            amount: T::Balance,
+        },
+
+        /// Bond unreserved back to the beneficiary
+        BondReturned {
+            escrow_id: T::EscrowId,
+            beneficiary: T::AccountId,
+            amount: T::Balance,
+        },
+
+        /// Bond paid to the creator after the escrow expired unredeemed
+        BondForfeited {
+            escrow_id: T::EscrowId,
+            creator: T::AccountId,
+            amount: T::Balance,
+        },
+
+        /// Escrow metadata replaced by its creator
+        MetadataUpdated {
+            escrow_id: T::EscrowId,
+        },
+
+        /// Escrow accounts of one asset were reconciled against active escrows
+        SolvencyChecked {
+            asset: AssetKey<T::AssetId>,
+            expected: u128,
+            actual: u128,
+        },
+
+        /// Protocol fee deducted from a completed escrow's payout
+        ProtocolFeeCharged {
+            escrow_id: T::EscrowId,
+            amount: T::Balance,
+        },
+
+        /// Timelock bounds for an asset type were overridden, or reset when `None`
+        AssetTimelockBoundsSet {
+            asset_type: AssetType,
+            bounds: Option<(BlockNumberFor<T>, BlockNumberFor<T>)>,
+        },
+
+        /// Stray funds left at a settled escrow's account were returned to its creator
+        SurplusSwept {
+            escrow_id: T::EscrowId,
+            creator: T::AccountId,
+            amount: T::Balance,
+        },
+
+        /// A funded escrow reached its timelock without being settled
+        EscrowNearExpiry {
+            escrow_id: T::EscrowId,
+        },
+
+        /// Beneficiary accepted an escrow's terms
+        EscrowAccepted {
+            escrow_id: T::EscrowId,
+            beneficiary: T::AccountId,
+        },
+
+        /// Creator role of an escrow handed to another account
+        EscrowOwnershipTransferred {
+            escrow_id: T::EscrowId,
+            old: T::AccountId,
+            new: T::AccountId,
+        },
+
+        /// Retention of revealed secrets was switched on or off
+        SecretRetentionSet {
+            retain: bool,
+        },
+
+        /// Governance recovered what it could from a stuck escrow account
+        EmergencyWithdrawn {
+            escrow_id: T::EscrowId,
+            dest: T::AccountId,
+            recovered: T::Balance,
+        },
+
+        /// Escrow template registered
+        TemplateRegistered {
+            template_id: u64,
+            owner: T::AccountId,
+        },
+
+        /// Accumulated protocol fees were paid out of the fee collector
+        FeesWithdrawn {
+            asset: AssetKey<T::AssetId>,
+            amount: u128,
+            dest: T::AccountId,
+        },
+
+        /// Expired escrow is held for disputes before its refund
+        RefundHeld {
+            escrow_id: T::EscrowId,
+            finalize_at: BlockNumberFor<T>,
+        },
+
+        /// Beneficiary contested a held refund, freezing the escrow for arbitration
+        DisputeRaised {
+            escrow_id: T::EscrowId,
+            beneficiary: T::AccountId,
+        },
+
+        /// Creator replaced the hashlock of an unfunded escrow
+        SecretHashRotated {
+            escrow_id: T::EscrowId,
+            hashlock: Vec<u8>,
+        },
+
+        /// Escrow frozen by an operator
+        EscrowFrozen {
+            escrow_id: T::EscrowId,
+        },
+
+        /// Escrow released from a freeze
+        EscrowUnfrozen {
+            escrow_id: T::EscrowId,
+        },
+
+        /// Active escrow paused by `PauseOrigin`
+        EscrowPaused {
+            escrow_id: T::EscrowId,
+        },
+
+        /// Paused escrow returned to `Active`
+        EscrowResumed {
+            escrow_id: T::EscrowId,
+        },
+
+        /// A revealed secret completed linked escrows; those in `failed` couldn't be settled
+        SecretRevealed {
+            hashlock: Vec<u8>,
+            completed: Vec<T::EscrowId>,
+            failed: Vec<T::EscrowId>,
+        },
+
+        /// Escrow completed successfully
+        EscrowCompleted {
+            escrow_id: T::EscrowId,
+            beneficiary: T::AccountId,
+            recipient: T::AccountId,
+            secret: Vec<u8>,
+            /// Ethereum order the maker linked at creation, if any
+            eth_order_hash: Option<[u8; 32]>,
+            at_block: BlockNumberFor<T>,
+            at_time: u64,
+        },
+
+        /// ABI-encoded `(bytes32 secret, bytes32 orderHash)` ready for the Ethereum contract
+        EthClaimPayload {
+            escrow_id: T::EscrowId,
+            payload: Vec<u8>,
+        },
+
+        /// Escrow cancelled or expired
+        EscrowCancelled {
+            escrow_id: T::EscrowId,
+            canceller: T::AccountId,
+            cause: CancelReason,
+            reason: Vec<u8>,
+            at_block: BlockNumberFor<T>,
+            at_time: u64,
+        },
+
+        /// Escrowed funds were routed to another chain on completion
+        XcmTransferInitiated {
+            escrow_id: T::EscrowId,
+            destination: MultiLocation,
+            asset: MultiAsset,
+            beneficiary: T::AccountId,
+            amount: T::Balance,
+            message_hash: XcmHash,
+        },
+
+        /// An asset was added to or removed from the allowlist
+        AllowedAssetSet {
+            asset: AssetKey<T::AssetId>,
+            allowed: bool,
+        },
+
+        /// Individual pause flags changed
+        PauseFlagsUpdated {
+            flags: PauseFlags,
+        },
+
+        /// A batch of completions was processed; failed items left their escrows untouched
+        BatchCompletionResult {
+            completed: Vec<T::EscrowId>,
+            failed: Vec<T::EscrowId>,
+        },
+
+        /// Expired escrows were refunded in bulk
+        ExpiredEscrowsSwept {
+            refunded: Vec<T::EscrowId>,
+            skipped: Vec<T::EscrowId>,
+        },
+
+        /// Terminal escrows past `RetentionPeriod` were removed during idle time
+        EscrowsPruned {
+            pruned: Vec<T::EscrowId>,
+        },
+
+        /// Maker set how much of an asset an agent may fund escrows with; zero revokes
+        FundingApprovalSet {
+            maker: T::AccountId,
+            agent: T::AccountId,
+            asset: AssetKey<T::AssetId>,
+            limit: T::Balance,
+        },
+
+        /// Relayer reward paid to the account that submitted an escrow's completion
+        RelayerRewardPaid {
+            escrow_id: T::EscrowId,
+            relayer: T::AccountId,
+            amount: T::Balance,
+        },
+
+        /// Vested part of a completed escrow's payout was paid to its recipient
+        VestedClaimed {
+            escrow_id: T::EscrowId,
+            recipient: T::AccountId,
+            amount: T::Balance,
+        },
+
+        /// A cancelled escrow was re-offered on the same terms under a new ID
+        EscrowRenewed {
+            previous_escrow_id: T::EscrowId,
+            escrow_id: T::EscrowId,
+        },
+
+        /// The recipient couldn't receive the payout, so it went to the fallback taker
+        PaidToFallback {
+            escrow_id: T::EscrowId,
+            primary: T::AccountId,
+            fallback: T::AccountId,
+            amount: T::Balance,
+        },
+
+        /// A maker signer approved cancelling an escrow
+        CancelApproved {
+            escrow_id: T::EscrowId,
+            signer: T::AccountId,
+            approvals: u32,
+        },
+
+        /// Emergency pause activated
+        EmergencyPauseActivated {
             activator: T::AccountId,
         },
 
-        /// Emergency pause deactivated
-        EmergencyPauseDeactivated {
-            deactivator: T::AccountId,
-        },
-    }
+        /// Emergency pause deactivated
+        EmergencyPauseDeactivated {
+            deactivator: T::AccountId,
+        },
+    }
+
+    /// Errors that can occur in the pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Escrow not found
+        EscrowNotFound,
+        /// Invalid escrow state for the requested operation
+        InvalidEscrowState,
+        /// Invalid hashlock provided
+        InvalidHashlock,
+        /// Timelock does not permit the operation yet, or a deadline is invalid
+        InvalidTimelock,
+        /// Timelock has expired
+        TimelockExpired,
+        /// Incorrect secret provided
+        IncorrectSecret,
+        /// Invalid secret format
+        InvalidSecret,
+        /// Not the escrow creator
+        NotCreator,
+        /// Not the escrow beneficiary
+        NotBeneficiary,
+        /// Insufficient balance for escrow
+        InsufficientBalance,
+        /// Asset not supported
+        AssetNotSupported,
+        /// Too many escrows for account
+        TooManyEscrows,
+        /// Invalid XCM route
+        InvalidXcmRoute,
+        /// XCM execution failed
+        XcmExecutionFailed,
+        /// Emergency pause is active
+        EmergencyPaused,
+        /// Operation not allowed during emergency pause or while the escrow is paused
+        OperationNotAllowed,
+        /// Invalid metadata
+        InvalidMetadata,
+        /// Arithmetic overflow
+        ArithmeticOverflow,
+        /// Escrow amount is below the configured minimum
+        AmountBelowMinimum,
+        /// An escrow with the requested ID already exists
+        EscrowIdCollision,
+        /// Beneficiary cannot be the escrow creator
+        InvalidBeneficiary,
+        /// Creator's completion authorization does not verify
+        InvalidAuthorization,
+        /// Bond amount must be non-zero
+        InvalidBond,
+        /// Escrow does not require a bond
+        NoBondRequired,
+        /// Bond has already been posted
+        BondAlreadyPosted,
+        /// Required bond has not been posted yet
+        BondNotPosted,
+        /// A live escrow from this creator already uses the hashlock with different terms
+        DuplicateHashlock,
+        /// Escrow is frozen by an operator
+        EscrowFrozen,
+        /// Recipient is neither the beneficiary nor pre-authorized by the escrow
+        RecipientNotAllowed,
+        /// Sequential escrow IDs have run out
+        EscrowIdExhausted,
+        /// Asset is not on the allowlist
+        AssetNotAllowed,
+        /// NFT escrow may not be paid out to this account
+        BeneficiaryNotAllowed,
+        /// Payout after fees would fall below the requested minimum
+        SlippageExceeded,
+        /// Too many escrows are active across the pallet
+        GlobalCapacityReached,
+        /// Oracle has no rate for the escrow's payout asset
+        PriceUnavailable,
+        /// Dispute window has not elapsed yet
+        DisputeWindowOpen,
+        /// Dispute window has already closed
+        DisputeWindowClosed,
+        /// More fees requested than have accumulated
+        InsufficientAccumulatedFees,
+        /// Amount is below what a fresh escrow account needs to exist
+        BelowExistentialDeposit,
+        /// Escrow account did not receive exactly the escrowed amount
+        FundingMismatch,
+        /// Timelock override has its minimum above its maximum
+        InvalidTimelockBounds,
+        /// Hash rounds must be between 1 and `MAX_HASH_ROUNDS`
+        InvalidHashRounds,
+        /// Timelock duration is below the minimum for the asset
+        TimelockTooShort,
+        /// Timelock duration is above the maximum for the asset
+        TimelockTooLong,
+        /// Escrow amount must be non-zero
+        AmountZero,
+        /// Template does not exist
+        TemplateNotFound,
+        /// Only the template's owner may use it
+        NotTemplateOwner,
+        /// Beneficiary has not accepted the escrow terms yet
+        NotAccepted,
+        /// Escrow account holds nothing beyond what the escrow accounts for
+        NoSurplus,
+        /// Funding would exceed what the maker approved the agent for
+        ApprovalExceeded,
+        /// Creator and beneficiary are flagged as related accounts
+        SelfDealingDetected,
+        /// Vesting must end after it starts and only applies to local fungible payouts held
+        /// in the escrow account
+        InvalidVestingSchedule,
+        /// Escrow has no vesting payout or nothing more has vested yet
+        NothingToClaim,
+        /// Relayer rewards must be non-zero and only apply to fungible escrows
+        InvalidRelayerReward,
+        /// Asset is not registered with `pallet_assets`, or an NFT escrow's amount isn't one
+        InvalidAsset,
+        /// Maker threshold must be between one and the number of distinct signers
+        InvalidMakerThreshold,
+        /// Escrow's maker is a threshold of signers; cancel through `approve_cancel`
+        MakerApprovalRequired,
+        /// Caller is not one of the escrow's maker signers
+        NotMakerSigner,
+        /// Signer has already approved cancelling this escrow
+        AlreadyApproved,
+        /// Creator has already created an escrow with this nonce
+        NonceAlreadyUsed,
+        /// Secret was revealed on the counterparty chain, so the escrow can't be refunded
+        RevealedElsewhere,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_terminal(now, remaining_weight)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new escrow with hashlock and timelock
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_escrow(metadata.len() as u32))]
+        pub fn create_escrow(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            asset: AssetInfo<T::AssetId>,
+            amount: T::Balance,
+            hashlock: Vec<u8>,
+            timelock_duration: BlockNumberFor<T>,
+            metadata: Vec<u8>,
+            xcm_route: Option<XcmRoute>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_create_escrow(
+                who,
+                None,
+                beneficiary,
+                asset,
+                amount,
+                hashlock,
+                timelock_duration,
+                metadata,
+                xcm_route,
+                Default::default(),
+            )
+        }
+
+        /// Fund an existing escrow
+        ///
+        /// Any account may supply the funds; refunds still go to the creator.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::fund_escrow())]
+        pub fn fund_escrow(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_fund_escrow(who, escrow_id)
+        }
+
+        /// Complete an escrow by providing the secret
+        ///
+        /// `secret` is capped at `MAX_SECRET_SIZE` bytes; longer ones don't decode. The call is
+        /// weighed as if the escrow were routed over XCM, and a local completion refunds the
+        /// difference.
+        #[pallet::call_index(2)]
+        #[pallet::weight(
+            T::WeightInfo::complete_escrow_xcm(secret.len() as u32).saturating_add(T::XcmWeightLimit::get())
+        )]
+        pub fn complete_escrow(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            secret: BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let secret_len = secret.len() as u32;
+            let routed = Self::routed_over_xcm(&escrow_id);
+            Self::do_complete_escrow(who.clone(), escrow_id, secret, who.clone(), Zero::zero(), who)?;
+
+            let actual_weight = if routed { None } else { Some(T::WeightInfo::complete_escrow(secret_len)) };
+            Ok(actual_weight.into())
+        }
+
+        /// Cancel an expired or invalid escrow
+        ///
+        /// `reason` is capped at `MAX_CANCEL_REASON_LEN` bytes; longer ones don't decode.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_escrow())]
+        pub fn cancel_escrow(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            reason: BoundedVec<u8, ConstU32<MAX_CANCEL_REASON_LEN>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_cancel_escrow(who, escrow_id, reason.into_inner(), false)
+        }
+
+        /// Emergency pause for security
+        ///
+        /// Applies the `PauseFlags::emergency` preset: creation and funding stop while
+        /// existing escrows can still complete or refund.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::emergency_pause())]
+        pub fn emergency_pause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin.clone())?;
+            // Non-signed pause origins are attributed to the pallet account
+            let who = ensure_signed(origin).unwrap_or_else(|_| Self::account_id());
+
+            Paused::<T>::put(PauseFlags::emergency());
+
+            Self::deposit_event(Event::EmergencyPauseActivated {
+                activator: who,
+            });
+
+            Ok(())
+        }
+
+        /// Deactivate emergency pause
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::emergency_unpause())]
+        pub fn emergency_unpause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin.clone())?;
+            let who = ensure_signed(origin).unwrap_or_else(|_| Self::account_id());
+
+            Paused::<T>::kill();
+
+            Self::deposit_event(Event::EmergencyPauseDeactivated {
+                deactivator: who,
+            });
+
+            Ok(())
+        }
+
+        /// Refund an expired escrow to its creator on their behalf
+        ///
+        /// Callable by any signed account so watchtowers can release funds for a creator who
+        /// has lost access to their key. The refund always goes to the creator, never the caller.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::claim_expired_refund())]
+        pub fn claim_expired_refund(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Self::pause_flags().cancel, Error::<T>::EmergencyPaused);
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Active, Error::<T>::InvalidEscrowState);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            ensure!(Self::refund_open(&escrow, current_block), Error::<T>::InvalidTimelock);
+
+            if Self::hold_for_dispute(&escrow_id, &mut escrow, current_block) {
+                return Ok(())
+            }
+            Self::refund_expired(&escrow_id, &mut escrow, current_block)?;
+
+            // Emit event
+            Self::deposit_event(Event::EscrowCancelled {
+                escrow_id,
+                canceller: who,
+                cause: CancelReason::RefundClaimedByThirdParty,
+                reason: b"Refund claimed by third party".to_vec(),
+                at_block: frame_system::Pallet::<T>::block_number(),
+                at_time: Self::now_unix_secs(),
+            });
+
+            Ok(())
+        }
+
+        /// Abandon an escrow that was never funded
+        ///
+        /// Only the creator may do this, at any time, and nothing is refunded since no funds
+        /// were ever transferred. The escrow is dropped from the creator's escrow index.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::cancel_before_funding())]
+        pub fn cancel_before_funding(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Self::pause_flags().cancel, Error::<T>::EmergencyPaused);
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state.awaiting_funding(), Error::<T>::InvalidEscrowState);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            Self::ensure_maker_approval(&escrow, current_block, false)?;
+            Self::settle_bond(&escrow_id, &mut escrow, false)?;
+
+            // Update escrow state
+            Self::set_state(&escrow_id, &mut escrow, EscrowState::Cancelled);
+            escrow.updated_at = current_block;
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            // Emit event
+            Self::deposit_event(Event::EscrowCancelled {
+                escrow_id,
+                canceller: who,
+                cause: CancelReason::CancelledBeforeFunding,
+                reason: b"Cancelled before funding".to_vec(),
+                at_block: frame_system::Pallet::<T>::block_number(),
+                at_time: Self::now_unix_secs(),
+            });
+
+            Ok(())
+        }
+
+        /// Change the amount of an escrow that has not been funded yet
+        ///
+        /// Lets the creator adjust for price movements without abandoning the hashlock.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::top_up_escrow())]
+        pub fn top_up_escrow(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            new_amount: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Created, Error::<T>::InvalidEscrowState);
+            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+            Self::ensure_min_amount(&escrow.asset, new_amount)?;
+
+            let old = escrow.amount;
+            escrow.amount = new_amount;
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::EscrowAmountUpdated {
+                escrow_id,
+                old,
+                new: new_amount,
+            });
+
+            Ok(())
+        }
+
+        /// Create a new escrow whose ID is derived from the hashlock and creator
+        ///
+        /// Relayers can compute the same ID on both chains instead of keeping a mapping.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::create_escrow_with_id(metadata.len() as u32))]
+        pub fn create_escrow_with_id(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            asset: AssetInfo<T::AssetId>,
+            amount: T::Balance,
+            hashlock: Vec<u8>,
+            timelock_duration: BlockNumberFor<T>,
+            metadata: Vec<u8>,
+            xcm_route: Option<XcmRoute>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let escrow_id = Self::derive_escrow_id(&hashlock, &who);
+            Self::do_create_escrow(
+                who,
+                Some(escrow_id),
+                beneficiary,
+                asset,
+                amount,
+                hashlock,
+                timelock_duration,
+                metadata,
+                xcm_route,
+                Default::default(),
+            )
+        }
+
+        /// Point an unfunded escrow at a different beneficiary
+        ///
+        /// Avoids a cancel-and-recreate that would burn the hashlock when the counterparty
+        /// address was wrong.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::reassign_beneficiary())]
+        pub fn reassign_beneficiary(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            new_beneficiary: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Created, Error::<T>::InvalidEscrowState);
+            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+            ensure!(new_beneficiary != who, Error::<T>::InvalidBeneficiary);
+            ensure!(!T::AntiSelfDeal::is_related(&who, &new_beneficiary), Error::<T>::SelfDealingDetected);
+
+            // A bond belongs to the old beneficiary, so hand it back
+            Self::settle_bond(&escrow_id, &mut escrow, false)?;
+
+            // Move the beneficiary index entry
+            BeneficiaryEscrows::<T>::mutate(&escrow.beneficiary, |escrows| escrows.retain(|id| id != &escrow_id));
+            BeneficiaryEscrows::<T>::try_mutate(&new_beneficiary, |escrows| {
+                escrows.try_push(escrow_id).map_err(|_| Error::<T>::TooManyEscrows)
+            })?;
+
+            let old = sp_std::mem::replace(&mut escrow.beneficiary, new_beneficiary.clone());
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::BeneficiaryReassigned {
+                escrow_id,
+                old,
+                new: new_beneficiary,
+            });
+
+            Ok(())
+        }
+
+        /// Pause or resume individual operations
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_pause_flags())]
+        pub fn set_pause_flags(origin: OriginFor<T>, flags: PauseFlags) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::put(flags);
+
+            Self::deposit_event(Event::PauseFlagsUpdated { flags });
+
+            Ok(())
+        }
+
+        /// Complete an escrow on the beneficiary's behalf using the creator's signed authorization
+        ///
+        /// For gasless flows: the creator signs
+        /// `(COMPLETE_AUTH_CONTEXT, genesis_hash, escrow_id, hashlock, sha2_256(secret))` off-chain
+        /// and any relayer may submit it, so the signature can't be replayed on another chain or
+        /// after the hashlock is rotated. Funds still go to the beneficiary. Weighed as an
+        /// XCM-routed completion; a local one refunds the difference.
+        #[pallet::call_index(12)]
+        #[pallet::weight(
+            T::WeightInfo::complete_escrow_with_auth().saturating_add(Pallet::<T>::xcm_completion_overhead())
+        )]
+        pub fn complete_escrow_with_auth(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            secret: BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>>,
+            creator_sig: T::OffchainSignature,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            let message =
+                (COMPLETE_AUTH_CONTEXT, genesis_hash, escrow_id, &escrow.hashlock, sp_core::hashing::sha2_256(&secret))
+                    .encode();
+            ensure!(
+                creator_sig.verify(&message[..], &escrow.creator),
+                Error::<T>::InvalidAuthorization
+            );
+
+            let routed = escrow.xcm_route.is_some();
+            Self::do_complete_escrow(
+                escrow.beneficiary.clone(),
+                escrow_id,
+                secret,
+                escrow.beneficiary,
+                Zero::zero(),
+                relayer,
+            )?;
+
+            let actual_weight = if routed { None } else { Some(T::WeightInfo::complete_escrow_with_auth()) };
+            Ok(actual_weight.into())
+        }
+
+        /// Create a new escrow with optional creation-time terms
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::create_escrow_with_options(metadata.len() as u32))]
+        pub fn create_escrow_with_options(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+            asset: AssetInfo<T::AssetId>,
+            amount: T::Balance,
+            hashlock: Vec<u8>,
+            timelock_duration: BlockNumberFor<T>,
+            metadata: Vec<u8>,
+            xcm_route: Option<XcmRoute>,
+            options: EscrowOptionsOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_create_escrow(
+                who,
+                None,
+                beneficiary,
+                asset,
+                amount,
+                hashlock,
+                timelock_duration,
+                metadata,
+                xcm_route,
+                options,
+            )
+        }
+
+        /// Reserve the bond an escrow requires of its beneficiary
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::post_bond())]
+        pub fn post_bond(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state.awaiting_funding(), Error::<T>::InvalidEscrowState);
+            ensure!(escrow.beneficiary == who, Error::<T>::NotBeneficiary);
+            let amount = escrow.options.bond.ok_or(Error::<T>::NoBondRequired)?;
+            ensure!(!escrow.bond_posted, Error::<T>::BondAlreadyPosted);
+
+            T::Currency::reserve(&who, amount)?;
+
+            escrow.bond_posted = true;
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::BondPosted {
+                escrow_id,
+                beneficiary: who,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Replace the metadata of a live escrow
+        ///
+        /// Lets the creator attach settlement details such as a destination-chain tx hash.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::update_metadata(metadata.len() as u32))]
+        pub fn update_metadata(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(
+                escrow.state == EscrowState::Active || escrow.state.awaiting_funding(),
+                Error::<T>::InvalidEscrowState
+            );
+            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+
+            escrow.metadata = metadata.try_into().map_err(|_| Error::<T>::InvalidMetadata)?;
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::MetadataUpdated { escrow_id });
+
+            Ok(())
+        }
+
+        /// Freeze a single escrow while a dispute is investigated
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::freeze_escrow())]
+        pub fn freeze_escrow(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            T::FreezeOrigin::ensure_origin(origin)?;
+            Self::set_frozen(escrow_id, true)
+        }
+
+        /// Release a frozen escrow
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::unfreeze_escrow())]
+        pub fn unfreeze_escrow(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            T::FreezeOrigin::ensure_origin(origin)?;
+            Self::set_frozen(escrow_id, false)
+        }
+
+        /// Reveal a secret once and complete every live escrow locked by its hash
+        ///
+        /// Serves multi-leg swaps where one secret governs several escrows. Each escrow pays
+        /// its own beneficiary; frozen or expired escrows are skipped, and one that fails to
+        /// settle is reported without reverting the others. The call fails only if none
+        /// settles. The secret is hashed
+        /// once with every supported algorithm, matching each single-round escrow against its own.
+        /// Every linked escrow is weighed as XCM-routed; those settled locally are refunded.
+        #[pallet::call_index(18)]
+        #[pallet::weight(
+            T::WeightInfo::reveal_secret(T::MaxLinkedReveals::get()).saturating_add(
+                Pallet::<T>::xcm_completion_overhead().saturating_mul(T::MaxLinkedReveals::get() as u64)
+            )
+        )]
+        pub fn reveal_secret(
+            origin: OriginFor<T>,
+            secret: BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>>,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+
+            // Collect first: completing an escrow removes it from the hashlock index
+            let mut eligible: Vec<(Vec<u8>, T::EscrowId, T::AccountId, bool)> = Vec::new();
+            for algorithm in HashAlgorithm::ALL {
+                let hashlock: BoundedVec<u8, ConstU32<MAX_HASHLOCK_SIZE>> = algorithm
+                    .hash(&secret)
+                    .to_vec()
+                    .try_into()
+                    .map_err(|_| Error::<T>::InvalidHashlock)?;
+                let remaining = (T::MaxLinkedReveals::get() as usize).saturating_sub(eligible.len());
+                let matched: Vec<_> = EscrowsByHashlock::<T>::iter_key_prefix(&hashlock)
+                    .filter_map(|id| Self::escrows(&id).map(|escrow| (id, escrow)))
+                    .filter(|(_, escrow)| {
+                        escrow.hash_algorithm == algorithm &&
+                            escrow.options.hash_rounds == 1 &&
+                            escrow.state == EscrowState::Active &&
+                            !escrow.frozen &&
+                            !Self::deadline_passed(escrow, current_block)
+                    })
+                    .map(|(id, escrow)| (hashlock.to_vec(), id, escrow.beneficiary, escrow.xcm_route.is_some()))
+                    .take(remaining)
+                    .collect();
+                eligible.extend(matched);
+            }
+            ensure!(!eligible.is_empty(), Error::<T>::EscrowNotFound);
+
+            // One event per hashlock unlocked, in the order the algorithms were tried
+            let mut revealed: Vec<(Vec<u8>, Vec<T::EscrowId>, Vec<T::EscrowId>)> = Vec::new();
+            let mut first_error = None;
+            let mut routed = 0u64;
+            for (hashlock, escrow_id, beneficiary, xcm_routed) in eligible {
+                if xcm_routed {
+                    routed = routed.saturating_add(1);
+                }
+                // Settled on its own, so an escrow that can't complete never blocks the others
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::do_complete_escrow(
+                        beneficiary.clone(),
+                        escrow_id,
+                        secret.clone(),
+                        beneficiary,
+                        Zero::zero(),
+                        relayer.clone(),
+                    )
+                });
+                if revealed.last().map_or(true, |(last, _, _)| *last != hashlock) {
+                    revealed.push((hashlock, Vec::new(), Vec::new()));
+                }
+                if let Some((_, completed, failed)) = revealed.last_mut() {
+                    match result {
+                        Ok(()) => completed.push(escrow_id),
+                        Err(error) => {
+                            failed.push(escrow_id);
+                            first_error.get_or_insert(error);
+                        },
+                    }
+                }
+            }
+            if revealed.iter().all(|(_, completed, _)| completed.is_empty()) {
+                if let Some(error) = first_error {
+                    return Err(error.into())
+                }
+            }
+
+            for (hashlock, completed, failed) in revealed {
+                Self::deposit_event(Event::SecretRevealed { hashlock, completed, failed });
+            }
+
+            let actual_weight = T::WeightInfo::reveal_secret(T::MaxLinkedReveals::get())
+                .saturating_add(Self::xcm_completion_overhead().saturating_mul(routed));
+            Ok(Some(actual_weight).into())
+        }
+
+        /// Refund every expired active escrow in `escrow_ids` to its creator
+        ///
+        /// Maintenance counterpart to `claim_expired_refund`. IDs that are unknown, not active,
+        /// frozen or still within their timelock, or whose refund fails, such as one whose secret
+        /// was revealed on the counterparty chain, are skipped and reported in the event. Lists
+        /// longer than `MaxSweep` don't decode.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::sweep_expired(escrow_ids.len() as u32))]
+        pub fn sweep_expired(origin: OriginFor<T>, escrow_ids: BoundedVec<T::EscrowId, T::MaxSweep>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Self::pause_flags().cancel, Error::<T>::EmergencyPaused);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let mut refunded = Vec::new();
+            let mut skipped = Vec::new();
+
+            for escrow_id in escrow_ids {
+                let mut escrow = match Self::escrows(&escrow_id) {
+                    Some(escrow)
+                        if escrow.state == EscrowState::Active &&
+                            !escrow.frozen &&
+                            escrow.options.dispute_window.is_none() &&
+                            Self::refund_open(&escrow, current_block) =>
+                        escrow,
+                    _ => {
+                        skipped.push(escrow_id);
+                        continue
+                    },
+                };
+
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::refund_expired(&escrow_id, &mut escrow, current_block)
+                });
+                if result.is_err() {
+                    skipped.push(escrow_id);
+                    continue
+                }
+                Self::deposit_event(Event::EscrowCancelled {
+                    escrow_id,
+                    canceller: who.clone(),
+                    cause: CancelReason::TimelockExpired,
+                    reason: b"Swept after expiry".to_vec(),
+                    at_block: frame_system::Pallet::<T>::block_number(),
+                    at_time: Self::now_unix_secs(),
+                });
+                refunded.push(escrow_id);
+            }
+
+            Self::deposit_event(Event::ExpiredEscrowsSwept { refunded, skipped });
+
+            Ok(())
+        }
+
+        /// Complete an escrow, paying out to `recipient` instead of the beneficiary's own account
+        ///
+        /// Only the beneficiary may call this, and `recipient` must be the beneficiary or one
+        /// of the `allowed_recipients` the creator fixed at creation. Fails unless at least
+        /// `min_amount_out` reaches the recipient after fees. Weighed as an XCM-routed
+        /// completion; a local one refunds the difference.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::complete_escrow_to().saturating_add(Pallet::<T>::xcm_completion_overhead()))]
+        pub fn complete_escrow_to(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            secret: BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>>,
+            recipient: T::AccountId,
+            min_amount_out: T::Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let routed = Self::routed_over_xcm(&escrow_id);
+            Self::do_complete_escrow(who.clone(), escrow_id, secret, recipient, min_amount_out, who)?;
+
+            let actual_weight = if routed { None } else { Some(T::WeightInfo::complete_escrow_to()) };
+            Ok(actual_weight.into())
+        }
+
+        /// Add an asset to, or remove it from, the creation allowlist
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_allowed_asset())]
+        pub fn set_allowed_asset(origin: OriginFor<T>, asset: AssetKey<T::AssetId>, allowed: bool) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let listed = AllowedAssets::<T>::contains_key(&asset);
+            if allowed && !listed {
+                AllowedAssets::<T>::insert(&asset, true);
+                AllowedAssetCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            } else if !allowed && listed {
+                AllowedAssets::<T>::remove(&asset);
+                AllowedAssetCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            }
+
+            Self::deposit_event(Event::AllowedAssetSet { asset, allowed });
+
+            Ok(())
+        }
+
+        /// Complete several escrows in one call, each with its own secret
+        ///
+        /// Items are settled independently: an item that fails, including one with a wrong
+        /// secret, is reported in the event without reverting the others. Every item is weighed
+        /// as XCM-routed; those settled locally are refunded. Lists longer than
+        /// `MaxBatchCompletions` don't decode.
+        #[pallet::call_index(22)]
+        #[pallet::weight(
+            T::WeightInfo::complete_escrow_batch(items.len() as u32)
+                .saturating_add(Pallet::<T>::xcm_completion_overhead().saturating_mul(items.len() as u64))
+        )]
+        pub fn complete_escrow_batch(
+            origin: OriginFor<T>,
+            items: BoundedVec<(T::EscrowId, BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>>), T::MaxBatchCompletions>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let count = items.len() as u32;
+            let mut completed = Vec::new();
+            let mut failed = Vec::new();
+            let mut routed = 0u64;
+            for (escrow_id, secret) in items {
+                if Self::routed_over_xcm(&escrow_id) {
+                    routed = routed.saturating_add(1);
+                }
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::do_complete_escrow(who.clone(), escrow_id, secret, who.clone(), Zero::zero(), who.clone())
+                });
+                if result.is_ok() {
+                    completed.push(escrow_id);
+                } else {
+                    failed.push(escrow_id);
+                }
+            }
+
+            Self::deposit_event(Event::BatchCompletionResult { completed, failed });
+
+            let actual_weight = T::WeightInfo::complete_escrow_batch(count)
+                .saturating_add(Self::xcm_completion_overhead().saturating_mul(routed));
+            Ok(Some(actual_weight).into())
+        }
+
+        /// Replace the hashlock of an unfunded escrow
+        ///
+        /// Fixes a hashlock that doesn't match the counterpart order without cancelling and
+        /// recreating. Accepts the same tagged formats as `create_escrow`.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::rotate_secret_hash())]
+        pub fn rotate_secret_hash(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            new_hashlock: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Created, Error::<T>::InvalidEscrowState);
+            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+
+            let (hash_algorithm, new_hashlock) = Self::split_hashlock(new_hashlock)?;
+            ensure!(!new_hashlock.is_empty(), Error::<T>::InvalidHashlock);
+            let new_hashlock: BoundedVec<u8, ConstU32<MAX_HASHLOCK_SIZE>> =
+                new_hashlock.try_into().map_err(|_| Error::<T>::InvalidHashlock)?;
+            let duplicate = EscrowsByHashlock::<T>::iter_key_prefix(&new_hashlock)
+                .filter(|id| *id != escrow_id)
+                .any(|id| Self::escrows(&id).map_or(false, |existing| existing.creator == who));
+            ensure!(!duplicate, Error::<T>::DuplicateHashlock);
+
+            EscrowsByHashlock::<T>::remove(&escrow.hashlock, &escrow_id);
+            EscrowsByHashlock::<T>::insert(&new_hashlock, &escrow_id, ());
+            escrow.hashlock = new_hashlock;
+            escrow.hash_algorithm = hash_algorithm;
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::SecretHashRotated { escrow_id, hashlock: escrow.hashlock.to_vec() });
+
+            Ok(())
+        }
+
+        /// Abort an escrow early by mutual agreement
+        ///
+        /// The beneficiary submits the creator's signature over
+        /// `(MUTUAL_CANCEL_CONTEXT, escrow_id)`. Funds go straight back to the creator
+        /// regardless of the timelock, and any bond is returned to the beneficiary.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::mutual_cancel())]
+        pub fn mutual_cancel(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            creator_sig: T::OffchainSignature,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Self::pause_flags().cancel, Error::<T>::EmergencyPaused);
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(
+                escrow.state == EscrowState::Active || escrow.state.awaiting_funding(),
+                Error::<T>::InvalidEscrowState
+            );
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+            ensure!(escrow.beneficiary == who, Error::<T>::NotBeneficiary);
+
+            let message = (MUTUAL_CANCEL_CONTEXT, escrow_id).encode();
+            ensure!(
+                creator_sig.verify(&message[..], &escrow.creator),
+                Error::<T>::InvalidAuthorization
+            );
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            Self::ensure_maker_approval(&escrow, current_block, false)?;
+
+            if escrow.state == EscrowState::Active {
+                Self::refund_creator(&escrow_id, &escrow)?;
+            }
+            Self::settle_bond(&escrow_id, &mut escrow, false)?;
+
+            Self::set_state(&escrow_id, &mut escrow, EscrowState::Cancelled);
+            escrow.updated_at = current_block;
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::EscrowCancelled {
+                escrow_id,
+                canceller: who,
+                cause: CancelReason::MutualAbort,
+                reason: b"Mutually agreed abort".to_vec(),
+                at_block: frame_system::Pallet::<T>::block_number(),
+                at_time: Self::now_unix_secs(),
+            });
+
+            Ok(())
+        }
+
+        /// Reconcile escrow account balances against active escrows, one event per asset
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::verify_solvency(T::MaxGlobalActiveEscrows::get()))]
+        pub fn verify_solvency(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            for AssetSolvency { asset, expected, actual } in Self::solvency_report() {
+                Self::deposit_event(Event::SolvencyChecked { asset, expected, actual });
+            }
+
+            Ok(())
+        }
+
+        /// Refund the creator of a `Disputable` escrow once its dispute window has elapsed
+        ///
+        /// Callable by any signed account. A disputed escrow stays frozen until `FreezeOrigin`
+        /// releases it.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::finalize_refund())]
+        pub fn finalize_refund(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Self::pause_flags().cancel, Error::<T>::EmergencyPaused);
+
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Disputable, Error::<T>::InvalidEscrowState);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let finalize_at = Self::refund_holds(&escrow_id).unwrap_or(current_block);
+            ensure!(current_block >= finalize_at, Error::<T>::DisputeWindowOpen);
+
+            RefundHolds::<T>::remove(&escrow_id);
+            Self::refund_expired(&escrow_id, &mut escrow, current_block)?;
+
+            Self::deposit_event(Event::EscrowCancelled {
+                escrow_id,
+                canceller: who,
+                cause: CancelReason::TimelockExpired,
+                reason: b"Refund finalized after dispute window".to_vec(),
+                at_block: current_block,
+                at_time: Self::now_unix_secs(),
+            });
+
+            Ok(())
+        }
+
+        /// Contest a held refund, freezing the escrow for arbitration
+        ///
+        /// Only the beneficiary may dispute, and only before the window closes.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::raise_dispute())]
+        pub fn raise_dispute(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Disputable, Error::<T>::InvalidEscrowState);
+            ensure!(escrow.beneficiary == who, Error::<T>::NotBeneficiary);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let finalize_at = Self::refund_holds(&escrow_id).unwrap_or(current_block);
+            ensure!(current_block < finalize_at, Error::<T>::DisputeWindowClosed);
+
+            Self::set_frozen(escrow_id, true)?;
+            Self::deposit_event(Event::DisputeRaised { escrow_id, beneficiary: who });
+
+            Ok(())
+        }
+
+        /// Pay accumulated protocol fees of one asset out of the fee collector
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::withdraw_fees())]
+        pub fn withdraw_fees(
+            origin: OriginFor<T>,
+            asset: AssetKey<T::AssetId>,
+            amount: T::Balance,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            T::FeeAdminOrigin::ensure_origin(origin)?;
+
+            let amount_u128: u128 = amount.saturated_into();
+            let accumulated = Self::accumulated_fees(asset);
+            ensure!(amount_u128 <= accumulated, Error::<T>::InsufficientAccumulatedFees);
+            let fungible = asset.as_fungible().ok_or(Error::<T>::AssetNotAllowed)?;
+
+            Self::transfer_asset(&fungible, &T::FeeCollector::get(), &dest, amount, Preservation::Preserve)?;
+            AccumulatedFees::<T>::insert(asset, accumulated.saturating_sub(amount_u128));
+
+            Self::deposit_event(Event::FeesWithdrawn { asset, amount: amount_u128, dest });
+
+            Ok(())
+        }
+
+        /// Store reusable escrow terms, emitting the new template's ID
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::register_template(params.metadata.len() as u32))]
+        pub fn register_template(origin: OriginFor<T>, params: TemplateParamsOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_timelock_in_bounds(&params.asset, params.timelock_duration)?;
+            ensure!(params.metadata.len() <= T::MaxMetadataLen::get() as usize, Error::<T>::InvalidMetadata);
+            if let Some(route) = &params.xcm_route {
+                Self::validate_xcm_route(route)?;
+            }
+
+            let template_id = Self::next_template_id();
+            NextTemplateId::<T>::put(template_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?);
+            EscrowTemplates::<T>::insert(template_id, (who.clone(), params));
+
+            Self::deposit_event(Event::TemplateRegistered { template_id, owner: who });
+
+            Ok(())
+        }
+
+        /// Create an escrow from one of the caller's templates
+        ///
+        /// Weighed for the longest metadata a template can hold.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::create_from_template(T::MaxMetadataLen::get()))]
+        pub fn create_from_template(
+            origin: OriginFor<T>,
+            template_id: u64,
+            hashlock: Vec<u8>,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let (owner, params) = Self::escrow_templates(template_id).ok_or(Error::<T>::TemplateNotFound)?;
+            ensure!(owner == who, Error::<T>::NotTemplateOwner);
+
+            Self::do_create_escrow(
+                who,
+                None,
+                params.beneficiary,
+                params.asset,
+                amount,
+                hashlock,
+                params.timelock_duration,
+                params.metadata,
+                params.xcm_route,
+                Default::default(),
+            )
+        }
+
+        /// Last-resort recovery of a funded escrow whose asset can no longer move normally
+        ///
+        /// Sends whatever the escrow account still holds to `dest` and cancels the escrow. A
+        /// failed transfer recovers nothing but still cancels, so deregistered assets can't
+        /// keep an escrow live forever.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::emergency_withdraw())]
+        pub fn emergency_withdraw(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state.holds_funds(), Error::<T>::InvalidEscrowState);
+
+            let held: T::Balance = Self::escrow_holdings(&escrow_id, &escrow).saturated_into();
+            let moved = frame_support::storage::with_storage_layer(|| {
+                Self::release_funds(&escrow_id, &escrow, &dest, held)
+            });
+            let recovered = if moved.is_ok() { held } else { Zero::zero() };
+
+            Self::settle_bond(&escrow_id, &mut escrow, false)?;
+            RefundHolds::<T>::remove(&escrow_id);
+            let current_block = frame_system::Pallet::<T>::block_number();
+            Self::set_state(&escrow_id, &mut escrow, EscrowState::Cancelled);
+            escrow.updated_at = current_block;
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::EmergencyWithdrawn { escrow_id, dest, recovered });
+            Self::deposit_event(Event::EscrowCancelled {
+                escrow_id,
+                canceller: Self::account_id(),
+                cause: CancelReason::ForcedByGovernance,
+                reason: b"Emergency withdrawal".to_vec(),
+                at_block: current_block,
+                at_time: Self::now_unix_secs(),
+            });
+
+            Ok(())
+        }
+
+        /// Switch retention of revealed secrets on or off
+        ///
+        /// Already retained secrets are kept when retention is switched off.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::set_secret_retention())]
+        pub fn set_secret_retention(origin: OriginFor<T>, retain: bool) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RetainRevealedSecrets::<T>::put(retain);
+
+            Self::deposit_event(Event::SecretRetentionSet { retain });
+
+            Ok(())
+        }
+
+        /// Override the timelock bounds for one asset type, or restore the global ones with `None`
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::set_asset_timelock_bounds())]
+        pub fn set_asset_timelock_bounds(
+            origin: OriginFor<T>,
+            asset_type: AssetType,
+            bounds: Option<(BlockNumberFor<T>, BlockNumberFor<T>)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match bounds {
+                Some((min, max)) => {
+                    ensure!(min <= max, Error::<T>::InvalidTimelockBounds);
+                    AssetTimelockBounds::<T>::insert(&asset_type, (min, max));
+                },
+                None => AssetTimelockBounds::<T>::remove(&asset_type),
+            }
+
+            Self::deposit_event(Event::AssetTimelockBoundsSet { asset_type, bounds });
+
+            Ok(())
+        }
+
+        /// Hand the creator role of an open escrow to another account
+        ///
+        /// Refunds follow the creator, so any later cancellation pays out to the new owner.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::transfer_escrow_ownership())]
+        pub fn transfer_escrow_ownership(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            new_creator: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(
+                escrow.state.awaiting_funding() || escrow.state == EscrowState::Active,
+                Error::<T>::InvalidEscrowState
+            );
+            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+            ensure!(new_creator != escrow.beneficiary, Error::<T>::InvalidBeneficiary);
+            ensure!(
+                !T::AntiSelfDeal::is_related(&new_creator, &escrow.beneficiary),
+                Error::<T>::SelfDealingDetected
+            );
+
+            // Move the creator index entry
+            AccountEscrows::<T>::mutate(&escrow.creator, |escrows| escrows.retain(|id| id != &escrow_id));
+            AccountEscrows::<T>::try_mutate(&new_creator, |escrows| {
+                escrows.try_push(escrow_id).map_err(|_| Error::<T>::TooManyEscrows)
+            })?;
+
+            let old = sp_std::mem::replace(&mut escrow.creator, new_creator.clone());
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::EscrowOwnershipTransferred {
+                escrow_id,
+                old,
+                new: new_creator,
+            });
+
+            Ok(())
+        }
+
+        /// Accept an unfunded escrow's terms as its beneficiary
+        ///
+        /// Terms that change the deal (amount, beneficiary, hashlock) are fixed from here on;
+        /// with `RequireTakerAcceptance` set, funding waits for this step.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::accept_escrow())]
+        pub fn accept_escrow(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Created, Error::<T>::InvalidEscrowState);
+            ensure!(escrow.beneficiary == who, Error::<T>::NotBeneficiary);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+
+            Self::set_state(&escrow_id, &mut escrow, EscrowState::Accepted);
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            Self::deposit_event(Event::EscrowAccepted { escrow_id, beneficiary: who });
+
+            Ok(())
+        }
+
+        /// Return whatever is left at a settled escrow's account to its creator
+        ///
+        /// Covers deposits made straight to the escrow account and assets that delivered more
+        /// than the escrowed amount. Anyone may trigger it; the funds only go to the creator.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::sweep_surplus())]
+        pub fn sweep_surplus(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(
+                matches!(escrow.state, EscrowState::Completed | EscrowState::Cancelled),
+                Error::<T>::InvalidEscrowState
+            );
+
+            // Once settled only an unclaimed vesting payout is still owed
+            let escrow_account = Self::escrow_account(&escrow_id);
+            let owed = Self::vesting_schedules(&escrow_id)
+                .map_or(Zero::zero(), |schedule| schedule.total.saturating_sub(schedule.claimed));
+            let surplus: T::Balance = Self::account_holdings(&escrow_account, &escrow.asset)
+                .saturated_into::<T::Balance>()
+                .saturating_sub(owed);
+            ensure!(!surplus.is_zero(), Error::<T>::NoSurplus);
+            Self::transfer_asset(&escrow.asset, &escrow_account, &escrow.creator, surplus, Preservation::Expendable)?;
+
+            Self::deposit_event(Event::SurplusSwept { escrow_id, creator: escrow.creator, amount: surplus });
+
+            Ok(())
+        }
+
+        /// Announce that a funded escrow hit its timelock, for off-chain refund tooling
+        ///
+        /// Dispatched by the scheduler at the timelock set on funding; a no-op once settled.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::notify_expiry())]
+        pub fn notify_expiry(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            if escrow.state == EscrowState::Active {
+                Self::deposit_event(Event::EscrowNearExpiry { escrow_id });
+            }
+
+            Ok(())
+        }
+
+        /// Let `agent` fund the caller's escrows in `asset`, up to `limit` in total
+        ///
+        /// Replaces any earlier approval for the pair; a zero `limit` revokes it.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::set_funding_approval())]
+        pub fn set_funding_approval(
+            origin: OriginFor<T>,
+            agent: T::AccountId,
+            asset: AssetKey<T::AssetId>,
+            limit: T::Balance,
+        ) -> DispatchResult {
+            let maker = ensure_signed(origin)?;
+
+            if limit.is_zero() {
+                FundingApprovals::<T>::remove(&maker, (agent.clone(), asset));
+            } else {
+                FundingApprovals::<T>::insert(&maker, (agent.clone(), asset), limit);
+            }
+
+            Self::deposit_event(Event::FundingApprovalSet { maker, agent, asset, limit });
+
+            Ok(())
+        }
+
+        /// Fund one of `maker`'s escrows from `maker`'s balance under an approval they granted
+        ///
+        /// The escrowed amount is deducted from the approval for the escrow's asset.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::fund_escrow_on_behalf())]
+        pub fn fund_escrow_on_behalf(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            maker: T::AccountId,
+        ) -> DispatchResult {
+            let agent = ensure_signed(origin)?;
+
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.creator == maker, Error::<T>::NotCreator);
+
+            FundingApprovals::<T>::try_mutate_exists(&maker, (agent, escrow.asset.key()), |approval| -> DispatchResult {
+                let remaining = approval.take().unwrap_or_default();
+                let total = Self::funding_total(&escrow);
+                ensure!(remaining >= total, Error::<T>::ApprovalExceeded);
+                *approval = Some(remaining - total).filter(|left| !left.is_zero());
+                Ok(())
+            })?;
+
+            Self::do_fund_escrow(maker, escrow_id)
+        }
+
+        /// Pause a single active escrow, blocking completion and cancellation until resumed
+        #[pallet::call_index(40)]
+        #[pallet::weight(T::WeightInfo::pause_escrow())]
+        pub fn pause_escrow(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            Self::set_paused(escrow_id, true)
+        }
+
+        /// Return a paused escrow to `Active`
+        #[pallet::call_index(41)]
+        #[pallet::weight(T::WeightInfo::resume_escrow())]
+        pub fn resume_escrow(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            Self::set_paused(escrow_id, false)
+        }
+
+        /// Pay out whatever has vested of a completed escrow's payout since the last claim
+        ///
+        /// Anyone may trigger it; the funds only go to the recorded recipient.
+        #[pallet::call_index(42)]
+        #[pallet::weight(T::WeightInfo::claim_vested())]
+        pub fn claim_vested(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut schedule = Self::vesting_schedules(&escrow_id).ok_or(Error::<T>::NothingToClaim)?;
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let amount = schedule.vested_at(now).saturating_sub(schedule.claimed);
+            ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+            // Keep the escrow account alive until the final claim empties it
+            schedule.claimed = schedule.claimed.saturating_add(amount);
+            let finished = schedule.claimed >= schedule.total;
+            let preservation = if finished { Preservation::Expendable } else { Preservation::Preserve };
+            let escrow_account = Self::escrow_account(&escrow_id);
+            Self::transfer_asset(&escrow.asset, &escrow_account, &schedule.recipient, amount, preservation)?;
+
+            if finished {
+                VestingSchedules::<T>::remove(&escrow_id);
+            } else {
+                VestingSchedules::<T>::insert(&escrow_id, &schedule);
+            }
+
+            Self::deposit_event(Event::VestedClaimed { escrow_id, recipient: schedule.recipient, amount });
+
+            Ok(())
+        }
+
+        /// Re-offer a cancelled escrow's terms with a fresh hashlock and timelock
+        ///
+        /// Copies the beneficiary, asset, amount, metadata and XCM route into a new escrow in
+        /// `Created`; other options start from their defaults. The new ID is in `EscrowRenewed`.
+        /// Weighed for the longest metadata the old escrow can hold.
+        #[pallet::call_index(43)]
+        #[pallet::weight(T::WeightInfo::renew_escrow(T::MaxMetadataLen::get()))]
+        pub fn renew_escrow(
+            origin: OriginFor<T>,
+            escrow_id: T::EscrowId,
+            new_hashlock: Vec<u8>,
+            timelock_duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state == EscrowState::Cancelled, Error::<T>::InvalidEscrowState);
+            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+
+            let new_escrow_id = Self::next_escrow_id();
+            Self::do_create_escrow(
+                who,
+                None,
+                escrow.beneficiary,
+                escrow.asset,
+                escrow.amount,
+                new_hashlock,
+                timelock_duration,
+                escrow.metadata.into_inner(),
+                escrow.xcm_route,
+                Default::default(),
+            )?;
+
+            // An identical resubmission creates nothing and leaves the counter alone
+            if Self::next_escrow_id() != new_escrow_id {
+                Self::deposit_event(Event::EscrowRenewed { previous_escrow_id: escrow_id, escrow_id: new_escrow_id });
+            }
+
+            Ok(())
+        }
+
+        /// Approve cancelling a threshold maker's escrow
+        ///
+        /// Once `threshold` distinct signers have approved, the escrow is cancelled and any
+        /// funds are refunded to the creator.
+        #[pallet::call_index(44)]
+        #[pallet::weight(T::WeightInfo::approve_cancel())]
+        pub fn approve_cancel(origin: OriginFor<T>, escrow_id: T::EscrowId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            let (threshold, signers) = escrow.options.maker_threshold.as_ref().ok_or(Error::<T>::NotMakerSigner)?;
+            ensure!(signers.contains(&who), Error::<T>::NotMakerSigner);
+            ensure!(
+                escrow.state == EscrowState::Active || escrow.state.awaiting_funding(),
+                Error::<T>::InvalidEscrowState
+            );
+
+            let approvals = CancelApprovals::<T>::try_mutate(&escrow_id, |approvals| -> Result<u32, DispatchError> {
+                ensure!(!approvals.contains(&who), Error::<T>::AlreadyApproved);
+                approvals.try_push(who.clone()).map_err(|_| Error::<T>::AlreadyApproved)?;
+                Ok(approvals.len() as u32)
+            })?;
+            Self::deposit_event(Event::CancelApproved { escrow_id, signer: who, approvals });
 
-    /// Errors that can occur in the pallet
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Escrow not found
-        EscrowNotFound,
-        /// Invalid escrow state for the requested operation
-        InvalidEscrowState,
-        /// Invalid hashlock provided
-        InvalidHashlock,
-        /// Invalid timelock duration
-        InvalidTimelock,
-        /// Timelock has expired
-        TimelockExpired,
-        /// Incorrect secret provided
-        IncorrectSecret,
-        /// Invalid secret format
-        InvalidSecret,
-        /// Not the escrow creator
-        NotCreator,
-        /// Not the escrow beneficiary
-        NotBeneficiary,
-        /// Insufficient balance for escrow
-        InsufficientBalance,
-        /// Asset not supported
-        AssetNotSupported,
-        /// Too many escrows for account
-        TooManyEscrows,
-        /// Invalid XCM route
-        InvalidXcmRoute,
-        /// XCM execution failed
-        XcmExecutionFailed,
-        /// Emergency pause is active
-        EmergencyPaused,
-        /// Operation not allowed during emergency pause
-        OperationNotAllowed,
-        /// Invalid metadata
-        InvalidMetadata,
-        /// Arithmetic overflow
-        ArithmeticOverflow,
+            if approvals >= u32::from(*threshold) {
+                Self::do_cancel_escrow(escrow.creator.clone(), escrow_id, b"Approved by maker signers".to_vec(), true)?;
+            }
+
+            Ok(())
+        }
     }
 
-    #[pallet::call]
+    // Helper methods
     impl<T: Config> Pallet<T> {
-        /// Create a new escrow with hashlock and timelock
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::create_escrow())]
-        pub fn create_escrow(
-            origin: OriginFor<T>,
+        /// Get the pallet's account ID
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Account holding an escrow's funds
+        ///
+        /// Salted escrows hash the pallet ID, escrow ID and salt together so the account can't
+        /// be predicted from the sequential ID; it stays reproducible from the stored salt.
+        pub fn escrow_account(escrow_id: &T::EscrowId) -> T::AccountId {
+            match Self::escrow_salts(escrow_id) {
+                Some(salt) => {
+                    let entropy = (T::PalletId::get(), escrow_id, salt).using_encoded(sp_core::hashing::blake2_256);
+                    Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+                        .expect("infinite length input; no invalid inputs for type; qed")
+                },
+                None => T::PalletId::get().into_sub_account_truncating(escrow_id),
+            }
+        }
+
+        /// Escrow ID both chains can compute from the hashlock and the creator
+        ///
+        /// Derived IDs carry `DERIVED_ESCROW_ID_FLAG`, so grinding a hashlock can never claim
+        /// an ID the counter has yet to hand out.
+        pub fn derive_escrow_id(hashlock: &[u8], creator: &T::AccountId) -> T::EscrowId {
+            let hash = (hashlock, creator).using_encoded(sp_core::hashing::blake2_256);
+            let id = u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]]);
+            T::EscrowId::from(u64::from(id) | DERIVED_ESCROW_ID_FLAG)
+        }
+
+        /// Validate and store a new escrow, allocating an ID from the counter when none is given
+        pub(crate) fn do_create_escrow(
+            who: T::AccountId,
+            escrow_id: Option<T::EscrowId>,
             beneficiary: T::AccountId,
             asset: AssetInfo<T::AssetId>,
             amount: T::Balance,
@@ -336,26 +2821,104 @@ pub mod pallet {
             timelock_duration: BlockNumberFor<T>,
             metadata: Vec<u8>,
             xcm_route: Option<XcmRoute>,
+            options: EscrowOptionsOf<T>,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            
             // Check emergency pause
-            ensure!(!Self::emergency_paused(), Error::<T>::EmergencyPaused);
+            ensure!(!Self::pause_flags().create, Error::<T>::EmergencyPaused);
 
             // Validate inputs
+            let (hash_algorithm, hashlock) = Self::split_hashlock(hashlock)?;
             ensure!(
                 !hashlock.is_empty() && hashlock.len() <= MAX_HASHLOCK_SIZE as usize,
                 Error::<T>::InvalidHashlock
             );
+            Self::ensure_timelock_in_bounds(&asset, timelock_duration)?;
             ensure!(
-                timelock_duration >= T::MinTimelockDuration::get() &&
-                timelock_duration <= T::MaxTimelockDuration::get(),
-                Error::<T>::InvalidTimelock
+                metadata.len() <= T::MaxMetadataLen::get() as usize,
+                Error::<T>::InvalidMetadata
             );
+            Self::ensure_min_amount(&asset, amount)?;
+            ensure!(Self::is_asset_allowed(&asset), Error::<T>::AssetNotAllowed);
+            ensure!(!T::AntiSelfDeal::is_related(&who, &beneficiary), Error::<T>::SelfDealingDetected);
+            if let Some(route) = &xcm_route {
+                Self::validate_xcm_route(route)?;
+            }
+            ensure!(options.bond.map_or(true, |bond| !bond.is_zero()), Error::<T>::InvalidBond);
+            ensure!(options.dispute_window.map_or(true, |window| !window.is_zero()), Error::<T>::InvalidTimelock);
             ensure!(
-                metadata.len() <= MAX_METADATA_SIZE as usize,
-                Error::<T>::InvalidMetadata
+                options.hash_rounds >= 1 && options.hash_rounds <= MAX_HASH_ROUNDS,
+                Error::<T>::InvalidHashRounds
+            );
+            if options.reserve_native {
+                ensure!(asset == AssetInfo::Native, Error::<T>::AssetNotSupported);
+                ensure!(xcm_route.is_none(), Error::<T>::InvalidXcmRoute);
+            }
+            if let Some((threshold, signers)) = &options.maker_threshold {
+                let distinct = signers.iter().enumerate().all(|(i, signer)| !signers[..i].contains(signer));
+                ensure!(
+                    *threshold >= 1 && usize::from(*threshold) <= signers.len() && distinct,
+                    Error::<T>::InvalidMakerThreshold
+                );
+            }
+            if let Some(fallback) = &options.fallback_taker {
+                ensure!(*fallback != who, Error::<T>::InvalidBeneficiary);
+                ensure!(!T::AntiSelfDeal::is_related(&who, fallback), Error::<T>::SelfDealingDetected);
+            }
+            if let Some(reward) = options.relayer_reward {
+                ensure!(
+                    !reward.is_zero() && asset.asset_type() != AssetType::Nft,
+                    Error::<T>::InvalidRelayerReward
+                );
+            }
+            if let Some((start, end)) = options.vesting {
+                ensure!(
+                    start < end &&
+                        asset.asset_type() != AssetType::Nft &&
+                        xcm_route.is_none() &&
+                        options.payout_asset.is_none() &&
+                        !options.reserve_native,
+                    Error::<T>::InvalidVestingSchedule
+                );
+            }
+            if let Some(payout_asset) = &options.payout_asset {
+                // Converted payouts are paid locally in a fungible asset
+                ensure!(xcm_route.is_none(), Error::<T>::InvalidXcmRoute);
+                ensure!(
+                    !matches!(payout_asset, AssetKey::NftCollection(_)) && Self::is_asset_key_allowed(payout_asset),
+                    Error::<T>::AssetNotAllowed
+                );
+            }
+            ensure!(
+                options.timelock_timestamp.map_or(true, |deadline| deadline > Self::now_unix_secs()),
+                Error::<T>::InvalidTimelock
             );
+            // Catch unregistered asset IDs now rather than with an opaque error at funding
+            if let AssetInfo::Asset(asset_id) | AssetInfo::Stablecoin { asset_id, .. } | AssetInfo::Bridged(asset_id) =
+                &asset
+            {
+                ensure!(T::Assets::asset_exists(*asset_id), Error::<T>::InvalidAsset);
+            }
+
+            if let Some(nonce) = options.nonce {
+                ensure!(!UsedNonces::<T>::contains_key(&who, nonce), Error::<T>::NonceAlreadyUsed);
+            }
+
+            // An identical resubmission is a no-op; a conflicting one is rejected
+            let hashlock: BoundedVec<u8, ConstU32<MAX_HASHLOCK_SIZE>> =
+                hashlock.try_into().map_err(|_| Error::<T>::InvalidHashlock)?;
+            for existing_id in EscrowsByHashlock::<T>::iter_key_prefix(&hashlock) {
+                let existing = match Self::escrows(&existing_id) {
+                    Some(existing) if existing.creator == who => existing,
+                    _ => continue,
+                };
+                let identical = existing.beneficiary == beneficiary &&
+                    existing.hash_algorithm == hash_algorithm &&
+                    existing.amount == amount &&
+                    existing.asset == asset &&
+                    existing.timelock.saturating_sub(existing.created_at) == timelock_duration;
+                ensure!(identical, Error::<T>::DuplicateHashlock);
+                return Ok(())
+            }
 
             // Check escrow limit
             let account_escrows = Self::account_escrows(&who);
@@ -364,12 +2927,23 @@ pub mod pallet {
                 Error::<T>::TooManyEscrows
             );
 
-            // Generate unique escrow ID
-            let escrow_id = Self::next_escrow_id();
-            let next_id = escrow_id.checked_add(&T::EscrowId::from(1u64)).ok_or(Error::<T>::ArithmeticOverflow)?;
+            // Generate unique escrow ID, falling back to the counter
+            let (escrow_id, next_id) = match escrow_id {
+                Some(id) => (id, None),
+                None => {
+                    let id = Self::next_escrow_id();
+                    let next = id.checked_add(&T::EscrowId::from(1u64)).ok_or(Error::<T>::EscrowIdExhausted)?;
+                    (id, Some(next))
+                },
+            };
+            ensure!(!Escrows::<T>::contains_key(&escrow_id), Error::<T>::EscrowIdCollision);
             
             let current_block = frame_system::Pallet::<T>::block_number();
             let timelock = current_block.saturating_add(timelock_duration);
+            ensure!(
+                options.maker_complete_after.map_or(true, |after| after < timelock),
+                Error::<T>::InvalidTimelock
+            );
 
             // Create escrow
             let escrow = Escrow {
@@ -378,301 +2952,1183 @@ pub mod pallet {
                 beneficiary: beneficiary.clone(),
                 asset: asset.clone(),
                 amount,
-                hashlock: hashlock.try_into().map_err(|_| Error::<T>::InvalidHashlock)?,
+                funder: None,
+                funded_at: None,
+                hashlock: hashlock.clone(),
+                hash_algorithm,
                 timelock,
                 state: EscrowState::Created,
                 metadata: metadata.try_into().map_err(|_| Error::<T>::InvalidMetadata)?,
                 xcm_route,
                 created_at: current_block,
                 updated_at: current_block,
+                options,
+                bond_posted: false,
+                frozen: false,
             };
 
             // Store escrow
+            let (salt, _) = T::Randomness::random(&(ESCROW_SALT_CONTEXT, &escrow_id, &who).encode());
+            EscrowSalts::<T>::insert(&escrow_id, salt);
             Escrows::<T>::insert(&escrow_id, &escrow);
+            EscrowsByState::<T>::insert(EscrowState::Created, &escrow_id, ());
+            EscrowsByHashlock::<T>::insert(&hashlock, &escrow_id, ());
+            if let Some(nonce) = escrow.options.nonce {
+                UsedNonces::<T>::insert(&who, nonce, ());
+            }
+            TotalCreated::<T>::mutate(|count| *count = count.saturating_add(1));
+            Self::record_event(&escrow_id, EscrowEventKind::Created);
             
             // Update account escrows
             AccountEscrows::<T>::try_mutate(&who, |escrows| {
                 escrows.try_push(escrow_id).map_err(|_| Error::<T>::TooManyEscrows)
             })?;
+            BeneficiaryEscrows::<T>::try_mutate(&beneficiary, |escrows| {
+                escrows.try_push(escrow_id).map_err(|_| Error::<T>::TooManyEscrows)
+            })?;
 
             // Update next escrow ID
-            NextEscrowId::<T>::put(next_id);
+            if let Some(next_id) = next_id {
+                NextEscrowId::<T>::put(next_id);
+            }
 
             // Emit event
             Self::deposit_event(Event::EscrowCreated {
                 escrow_id,
                 creator: who,
                 beneficiary,
+                normalized_amount: Self::normalized_amount(&asset, amount),
                 asset,
                 amount,
                 timelock,
+                escrow_account: Self::escrow_account(&escrow_id),
+                at_block: frame_system::Pallet::<T>::block_number(),
+                at_time: Self::now_unix_secs(),
             });
 
             Ok(())
         }
 
-        /// Fund an existing escrow
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::fund_escrow())]
-        pub fn fund_escrow(
-            origin: OriginFor<T>,
+        /// Cancel an escrow on behalf of `who`
+        ///
+        /// `approved` marks a threshold maker's cancellation that its signers have signed off.
+        pub(crate) fn do_cancel_escrow(
+            who: T::AccountId,
             escrow_id: T::EscrowId,
+            reason: Vec<u8>,
+            approved: bool,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            ensure!(!Self::pause_flags().cancel, Error::<T>::EmergencyPaused);
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state != EscrowState::Paused, Error::<T>::OperationNotAllowed);
+            ensure!(
+                escrow.state == EscrowState::Active || escrow.state.awaiting_funding(),
+                Error::<T>::InvalidEscrowState
+            );
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
             
+            // Check permissions: creator can cancel anytime, others only once the refund window opens.
+            // A threshold maker needs its signers' approval to cancel early.
+            // Between the deadline and the end of the safety gap nobody may cancel a funded escrow,
+            // so a claim landing at the deadline never races a refund.
+            let in_safety_gap = escrow.state == EscrowState::Active &&
+                Self::deadline_passed(&escrow, current_block) &&
+                !Self::refund_open(&escrow, current_block);
+            ensure!(!in_safety_gap, Error::<T>::InvalidTimelock);
+            if who != escrow.creator {
+                ensure!(Self::refund_open(&escrow, current_block), Error::<T>::InvalidTimelock);
+            } else {
+                Self::ensure_maker_approval(&escrow, current_block, approved)?;
+            }
+
+            // Refund if escrow is active; a beneficiary who let it expire loses their bond
+            let expired_unredeemed =
+                escrow.state == EscrowState::Active && Self::deadline_passed(&escrow, current_block);
+            if expired_unredeemed && Self::hold_for_dispute(&escrow_id, &mut escrow, current_block) {
+                return Ok(())
+            }
+            let cause = if escrow.state.awaiting_funding() {
+                CancelReason::CancelledBeforeFunding
+            } else if expired_unredeemed {
+                CancelReason::TimelockExpired
+            } else {
+                CancelReason::CancelledByCreator
+            };
+            if escrow.state == EscrowState::Active {
+                Self::refund_creator(&escrow_id, &escrow)?;
+            }
+            Self::settle_bond(&escrow_id, &mut escrow, expired_unredeemed)?;
+
+            // Update escrow state
+            Self::ensure_state_unchanged(&escrow_id, &escrow)?;
+            Self::set_state(&escrow_id, &mut escrow, EscrowState::Cancelled);
+            escrow.updated_at = current_block;
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            // Emit event
+            Self::deposit_event(Event::EscrowCancelled {
+                escrow_id,
+                canceller: who,
+                cause,
+                reason,
+                at_block: frame_system::Pallet::<T>::block_number(),
+                at_time: Self::now_unix_secs(),
+            });
+
+            Ok(())
+        }
+
+        /// Fund an escrow with `who`'s balance
+        pub(crate) fn do_fund_escrow(who: T::AccountId, escrow_id: T::EscrowId) -> DispatchResult {
             // Check emergency pause
-            ensure!(!Self::emergency_paused(), Error::<T>::EmergencyPaused);
+            ensure!(!Self::pause_flags().fund, Error::<T>::EmergencyPaused);
 
             // Get and validate escrow
             let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
-            ensure!(escrow.state == EscrowState::Created, Error::<T>::InvalidEscrowState);
-            ensure!(escrow.creator == who, Error::<T>::NotCreator);
+            ensure!(escrow.state.awaiting_funding(), Error::<T>::InvalidEscrowState);
+            ensure!(
+                !T::RequireTakerAcceptance::get() || escrow.state == EscrowState::Accepted,
+                Error::<T>::NotAccepted
+            );
+            ensure!(escrow.options.bond.is_none() || escrow.bond_posted, Error::<T>::BondNotPosted);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            ensure!(!Self::deadline_passed(&escrow, current_block), Error::<T>::TimelockExpired);
+            ensure!(
+                Self::total_active() < T::MaxGlobalActiveEscrows::get() as u64,
+                Error::<T>::GlobalCapacityReached
+            );
+
+            // Reserve-mode funds stay with the funder; otherwise they move to the escrow
+            // account, and a fresh one only comes into existence with at least the existential
+            // deposit
+            escrow.funder = Some(who.clone());
+            let total = Self::funding_total(&escrow);
+            let held_before = Self::escrow_holdings(&escrow_id, &escrow);
+            if escrow.options.reserve_native {
+                T::Currency::reserve_named(&Self::reserve_id(&escrow_id), &who, total)?;
+            } else {
+                ensure!(
+                    held_before > 0 || total >= Self::minimum_balance(&escrow.asset),
+                    Error::<T>::BelowExistentialDeposit
+                );
+                let escrow_account = Self::escrow_account(&escrow_id);
+                let preservation = Self::preservation_for(&escrow.asset);
+                Self::transfer_asset(&escrow.asset, &who, &escrow_account, total, preservation)?;
+            }
+            let received = Self::escrow_holdings(&escrow_id, &escrow).saturating_sub(held_before);
+            ensure!(received == total.saturated_into::<u128>(), Error::<T>::FundingMismatch);
+
+            // Update escrow state
+            escrow.funded_at = Some(current_block);
+            escrow.updated_at = current_block;
+            Self::set_state(&escrow_id, &mut escrow, EscrowState::Active);
+            Escrows::<T>::insert(&escrow_id, &escrow);
+            Self::schedule_expiry_notice(&escrow_id, escrow.timelock);
+
+            // Emit event
+            Self::deposit_event(Event::EscrowFunded {
+                escrow_id,
+                funder: who,
+                at_block: frame_system::Pallet::<T>::block_number(),
+                at_time: Self::now_unix_secs(),
+            });
+
+            Ok(())
+        }
+
+        /// Release an active escrow on behalf of `who`, which must be its beneficiary
+        ///
+        /// Pays `recipient` the escrowed amount less the protocol fee, provided that is at
+        /// least `min_amount_out`, and any relayer reward to `submitter`, the account that
+        /// sent the transaction.
+        pub(crate) fn do_complete_escrow(
+            who: T::AccountId,
+            escrow_id: T::EscrowId,
+            secret: BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>>,
+            recipient: T::AccountId,
+            min_amount_out: T::Balance,
+            submitter: T::AccountId,
+        ) -> DispatchResult {
+            // Check emergency pause
+            ensure!(!Self::pause_flags().complete, Error::<T>::EmergencyPaused);
+
+            // Get and validate escrow
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.state != EscrowState::Paused, Error::<T>::OperationNotAllowed);
+            ensure!(escrow.state == EscrowState::Active, Error::<T>::InvalidEscrowState);
+            ensure!(!escrow.frozen, Error::<T>::EscrowFrozen);
+
+            // After the grace block the maker may settle on the taker's behalf, paying the taker
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let maker_settling = who != escrow.beneficiary &&
+                who == escrow.creator &&
+                escrow.options.maker_complete_after.map_or(false, |after| current_block >= after);
+            ensure!(escrow.beneficiary == who || maker_settling, Error::<T>::NotBeneficiary);
+            let mut recipient = if maker_settling { escrow.beneficiary.clone() } else { recipient };
+            ensure!(
+                recipient == escrow.beneficiary || escrow.options.allowed_recipients.contains(&recipient),
+                Error::<T>::RecipientNotAllowed
+            );
+            ensure!(
+                escrow.asset.asset_type() != AssetType::Nft ||
+                    escrow.options.nft_claimants.is_empty() ||
+                    escrow.options.nft_claimants.contains(&recipient),
+                Error::<T>::BeneficiaryNotAllowed
+            );
+            ensure!(!Self::deadline_passed(&escrow, current_block), Error::<T>::TimelockExpired);
+
+            let fee = Self::protocol_fee(&escrow.asset, escrow.amount);
+            let payout = escrow.amount.saturating_sub(fee);
+            let conversion = match escrow.options.payout_asset {
+                Some(payout_asset) if payout_asset != escrow.asset.key() => {
+                    let converted = T::PriceOracle::convert(&escrow.asset.key(), &payout_asset, payout)
+                        .ok_or(Error::<T>::PriceUnavailable)?;
+                    Some((payout_asset, converted))
+                },
+                _ => None,
+            };
+            let amount_out = conversion.map_or(payout, |(_, converted)| converted);
+            ensure!(amount_out >= min_amount_out, Error::<T>::SlippageExceeded);
+
+            // Verify secret against hashlock
+            let secret_hash = escrow.hash_algorithm.hash_rounds(&secret, escrow.options.hash_rounds);
+            ensure!(secret_hash.to_vec() == escrow.hashlock.to_vec(), Error::<T>::IncorrectSecret);
+
+            // Take the protocol fee, then pay the recipient or route cross-chain when requested
+            if !fee.is_zero() {
+                Self::release_funds(&escrow_id, &escrow, &T::FeeCollector::get(), fee)?;
+                AccumulatedFees::<T>::mutate(escrow.asset.key(), |total| {
+                    *total = total.saturating_add(fee.saturated_into())
+                });
+                Self::deposit_event(Event::ProtocolFeeCharged { escrow_id, amount: fee });
+            }
+            if let Some(reward) = escrow.options.relayer_reward {
+                Self::release_funds(&escrow_id, &escrow, &submitter, reward)?;
+                Self::deposit_event(Event::RelayerRewardPaid { escrow_id, relayer: submitter, amount: reward });
+            }
+            if let Some((payout_asset, converted)) = conversion {
+                // The pool takes the locked asset and pays the recipient in the payout asset
+                let pool = T::ConversionPool::get();
+                let payout_asset = payout_asset.as_fungible().ok_or(Error::<T>::AssetNotAllowed)?;
+                Self::release_funds(&escrow_id, &escrow, &pool, payout)?;
+                Self::transfer_asset(&payout_asset, &pool, &recipient, converted, Preservation::Preserve)?;
+            } else if let Some(route) = &escrow.xcm_route {
+                Self::route_via_xcm(&escrow_id, &escrow, route, &recipient, payout)?;
+            } else if let Some((start, end)) = escrow.options.vesting {
+                // The payout stays in the escrow account until claimed
+                VestingSchedules::<T>::insert(
+                    &escrow_id,
+                    VestingSchedule { recipient: recipient.clone(), total: payout, claimed: Zero::zero(), start, end },
+                );
+            } else {
+                let paid = frame_support::storage::with_storage_layer(|| {
+                    Self::release_funds(&escrow_id, &escrow, &recipient, payout)
+                });
+                match (paid, escrow.options.fallback_taker.clone()) {
+                    (Ok(()), _) => {},
+                    (Err(_), Some(fallback)) => {
+                        Self::release_funds(&escrow_id, &escrow, &fallback, payout)?;
+                        Self::deposit_event(Event::PaidToFallback {
+                            escrow_id,
+                            primary: recipient,
+                            fallback: fallback.clone(),
+                            amount: payout,
+                        });
+                        recipient = fallback;
+                    },
+                    (Err(error), None) => return Err(error),
+                }
+            }
+
+            Self::settle_bond(&escrow_id, &mut escrow, false)?;
+
+            // Update escrow state
+            Self::ensure_state_unchanged(&escrow_id, &escrow)?;
+            Self::set_state(&escrow_id, &mut escrow, EscrowState::Completed);
+            escrow.updated_at = current_block;
+            Escrows::<T>::insert(&escrow_id, &escrow);
+            if let Some(funded_at) = escrow.funded_at {
+                Self::record_completion_time(current_block.saturating_sub(funded_at).saturated_into());
+            }
+
+            if Self::retain_revealed_secrets() {
+                if let Ok(retained) = BoundedVec::<u8, ConstU32<MAX_RETAINED_SECRET_SIZE>>::try_from(secret.to_vec()) {
+                    RevealedSecrets::<T>::insert(&escrow_id, retained);
+                }
+            }
+
+            let eth_payload = if T::EmitEthClaimPayload::get() {
+                let order_hash = escrow.options.eth_order_hash.as_ref().map_or(&escrow.hashlock[..], |hash| &hash[..]);
+                Self::eth_claim_payload(&secret, order_hash)
+            } else {
+                None
+            };
+
+            // Emit event
+            Self::deposit_event(Event::EscrowCompleted {
+                escrow_id,
+                beneficiary: escrow.beneficiary.clone(),
+                recipient,
+                secret: secret.into_inner(),
+                eth_order_hash: escrow.options.eth_order_hash,
+                at_block: frame_system::Pallet::<T>::block_number(),
+                at_time: Self::now_unix_secs(),
+            });
+
+            if let Some(payload) = eth_payload {
+                Self::deposit_event(Event::EthClaimPayload { escrow_id, payload });
+            }
+
+            Ok(())
+        }
+
+        /// Remove completed and cancelled escrows older than `RetentionPeriod`
+        ///
+        /// Visits as many escrows as `remaining_weight` pays for, in storage order, and leaves
+        /// a cursor so the next idle block picks up where this one stopped.
+        pub(crate) fn prune_terminal(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let overhead = T::DbWeight::get().reads_writes(1, 1);
+            let per_escrow = T::WeightInfo::prune_escrow();
+            if !remaining_weight.all_gte(overhead.saturating_add(per_escrow)) {
+                return Weight::zero()
+            }
+            let budget = remaining_weight.saturating_sub(overhead);
+            let max_visits = budget
+                .ref_time()
+                .checked_div(per_escrow.ref_time())
+                .unwrap_or(u64::MAX)
+                .min(budget.proof_size().checked_div(per_escrow.proof_size()).unwrap_or(u64::MAX));
+
+            // Collect first so the map isn't mutated mid-iteration
+            let iter = match PruneCursor::<T>::get() {
+                Some(id) => Escrows::<T>::iter_from(Escrows::<T>::hashed_key_for(id)),
+                None => Escrows::<T>::iter(),
+            };
+            let visited: Vec<_> = iter.take(max_visits as usize).collect();
+            match visited.last() {
+                Some((id, _)) if visited.len() as u64 == max_visits => PruneCursor::<T>::put(id),
+                _ => PruneCursor::<T>::kill(),
+            }
+
+            let retention = T::RetentionPeriod::get();
+            let mut pruned = Vec::new();
+            for (escrow_id, escrow) in visited.iter() {
+                // The escrow account must stay derivable while a payout is still vesting
+                let terminal = matches!(escrow.state, EscrowState::Completed | EscrowState::Cancelled) &&
+                    !VestingSchedules::<T>::contains_key(escrow_id);
+                if terminal && now >= escrow.updated_at.saturating_add(retention) {
+                    Escrows::<T>::remove(escrow_id);
+                    EscrowsByState::<T>::remove(&escrow.state, escrow_id);
+                    EscrowSalts::<T>::remove(escrow_id);
+                    RefundHolds::<T>::remove(escrow_id);
+                    RevealedSecrets::<T>::remove(escrow_id);
+                    pruned.push(*escrow_id);
+                }
+            }
+            if !pruned.is_empty() {
+                Self::deposit_event(Event::EscrowsPruned { pruned });
+            }
+
+            overhead.saturating_add(per_escrow.saturating_mul(visited.len() as u64))
+        }
+
+        /// Scheduler task name for an escrow's expiry notification
+        pub fn expiry_task_name(escrow_id: &T::EscrowId) -> [u8; 32] {
+            (EXPIRY_TASK_CONTEXT, escrow_id).using_encoded(sp_core::hashing::blake2_256)
+        }
+
+        /// Schedule `notify_expiry` for `at`; a full agenda only loses the notice, never the funding
+        pub(crate) fn schedule_expiry_notice(escrow_id: &T::EscrowId, at: BlockNumberFor<T>) {
+            let call = <T as Config>::RuntimeCall::from(Call::<T>::notify_expiry { escrow_id: *escrow_id });
+            let inline = match call.encode().try_into() {
+                Ok(inline) => inline,
+                Err(_) => return,
+            };
+            let _ = T::Scheduler::schedule_named(
+                Self::expiry_task_name(escrow_id),
+                DispatchTime::At(at),
+                None,
+                LOWEST_PRIORITY,
+                frame_system::RawOrigin::Root.into(),
+                Bounded::Inline(inline),
+            );
+        }
+
+        /// Fold a fund-to-completion duration into `AvgCompletionBlocks`
+        ///
+        /// Each sample moves the average `1 / COMPLETION_EMA_WEIGHT` of the way toward it.
+        pub(crate) fn record_completion_time(blocks: u64) {
+            AvgCompletionBlocks::<T>::mutate(|avg| {
+                *avg = Some(match *avg {
+                    Some(avg) => avg
+                        .saturating_mul(COMPLETION_EMA_WEIGHT - 1)
+                        .saturating_add(blocks) /
+                        COMPLETION_EMA_WEIGHT,
+                    None => blocks,
+                })
+            });
+        }
+
+        /// Re-read the stored escrow and reject if its state moved since `escrow` was loaded
+        ///
+        /// Transfers, hooks and XCM run between the initial read and the state write, so the
+        /// terminal transition is only taken against the state the checks were made on.
+        pub(crate) fn ensure_state_unchanged(escrow_id: &T::EscrowId, escrow: &Escrow<T>) -> DispatchResult {
+            let stored = Self::escrows(escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(stored.state == escrow.state, Error::<T>::InvalidEscrowState);
+            Ok(())
+        }
+
+        /// Escrowed amount in DOT, `None` if the oracle has no rate for the asset
+        pub(crate) fn dot_value(escrow: &Escrow<T>) -> Option<u128> {
+            match escrow.asset.key() {
+                AssetKey::Native => Some(escrow.amount.saturated_into()),
+                key => T::PriceOracle::convert(&key, &AssetKey::Native, escrow.amount).map(|v| v.saturated_into()),
+            }
+        }
+
+        /// Transition an escrow to a new state, keeping the state index in sync
+        pub(crate) fn set_state(escrow_id: &T::EscrowId, escrow: &mut Escrow<T>, new_state: EscrowState) {
+            // Funds sit in the escrow account exactly while it is Active, Paused or Disputable
+            let amount: u128 = escrow.amount.saturated_into();
+            let asset_type = escrow.asset.asset_type();
+            if !escrow.state.holds_funds() && new_state.holds_funds() {
+                TotalValueLocked::<T>::mutate(&asset_type, |tvl| *tvl = tvl.saturating_add(amount));
+                TotalActive::<T>::mutate(|count| *count = count.saturating_add(1));
+                if let Some(value) = Self::dot_value(escrow) {
+                    TvlInDot::<T>::mutate(|tvl| *tvl = tvl.saturating_add(value));
+                    EscrowDotValue::<T>::insert(escrow_id, value);
+                }
+            } else if escrow.state.holds_funds() && !new_state.holds_funds() {
+                TotalValueLocked::<T>::mutate(&asset_type, |tvl| *tvl = tvl.saturating_sub(amount));
+                TotalActive::<T>::mutate(|count| *count = count.saturating_sub(1));
+                if let Some(value) = EscrowDotValue::<T>::take(escrow_id) {
+                    TvlInDot::<T>::mutate(|tvl| *tvl = tvl.saturating_sub(value));
+                }
+                // Settled early, or the notice already fired; nothing to cancel is fine
+                let _ = T::Scheduler::cancel_named(Self::expiry_task_name(escrow_id));
+            }
+
+            // Per-account indexes only cap live escrows, so terminal ones drop out
+            if matches!(new_state, EscrowState::Completed | EscrowState::Cancelled) {
+                AccountEscrows::<T>::mutate(&escrow.creator, |escrows| escrows.retain(|id| id != escrow_id));
+                BeneficiaryEscrows::<T>::mutate(&escrow.beneficiary, |escrows| escrows.retain(|id| id != escrow_id));
+                EscrowsByHashlock::<T>::remove(&escrow.hashlock, escrow_id);
+                CancelApprovals::<T>::remove(escrow_id);
+            }
+
+            // Resuming a paused escrow is not a new funding
+            let funding = escrow.state.awaiting_funding() && new_state == EscrowState::Active;
+            match new_state {
+                EscrowState::Active if funding => Self::record_event(escrow_id, EscrowEventKind::Funded),
+                EscrowState::Completed => {
+                    TotalCompleted::<T>::mutate(|count| *count = count.saturating_add(1));
+                    Self::record_event(escrow_id, EscrowEventKind::Completed)
+                },
+                EscrowState::Cancelled => {
+                    TotalCancelled::<T>::mutate(|count| *count = count.saturating_add(1));
+                    Self::record_event(escrow_id, EscrowEventKind::Cancelled)
+                },
+                EscrowState::Active |
+                EscrowState::Created |
+                EscrowState::Accepted |
+                EscrowState::Paused |
+                EscrowState::Disputable => {},
+            }
+
+            EscrowsByState::<T>::remove(&escrow.state, escrow_id);
+            EscrowsByState::<T>::insert(&new_state, escrow_id, ());
+            escrow.state = new_state;
+
+            match escrow.state {
+                EscrowState::Active if funding => T::OnEscrowEvent::on_funded(escrow_id, escrow),
+                EscrowState::Completed => T::OnEscrowEvent::on_completed(escrow_id, escrow),
+                EscrowState::Cancelled => T::OnEscrowEvent::on_cancelled(escrow_id, escrow),
+                EscrowState::Active |
+                EscrowState::Created |
+                EscrowState::Accepted |
+                EscrowState::Paused |
+                EscrowState::Disputable => {},
+            }
+        }
+
+        /// Reject dust escrows, and NFT escrows whose amount isn't exactly one
+        pub(crate) fn ensure_min_amount(asset: &AssetInfo<T::AssetId>, amount: T::Balance) -> DispatchResult {
+            if asset.asset_type() == AssetType::Nft {
+                // An NFT escrow holds exactly one item
+                ensure!(amount == One::one(), Error::<T>::InvalidAsset);
+            } else {
+                ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+                ensure!(amount >= T::MinEscrowAmount::get(), Error::<T>::AmountBelowMinimum);
+            }
+            Ok(())
+        }
+
+        /// Whether escrows may be created in `asset` under the current allowlist
+        pub fn is_asset_allowed(asset: &AssetInfo<T::AssetId>) -> bool {
+            Self::is_asset_key_allowed(&asset.key())
+        }
 
-            let current_block = frame_system::Pallet::<T>::block_number();
-            ensure!(current_block < escrow.timelock, Error::<T>::TimelockExpired);
+        /// Whether `key` is usable under the current allowlist
+        pub fn is_asset_key_allowed(key: &AssetKey<T::AssetId>) -> bool {
+            if AllowedAssetCount::<T>::get() == 0 {
+                return T::AllowAllWhenUnlisted::get()
+            }
+            Self::allowed_assets(key)
+        }
 
-            // Transfer funds to escrow account
-            let escrow_account = Self::escrow_account(&escrow_id);
-            
-            match &escrow.asset {
+        /// Move `amount` of the escrowed asset between two accounts
+        ///
+        /// Payouts from an escrow account are always `Expendable`: the account is single-use
+        /// and holds exactly the escrowed amount.
+        pub(crate) fn transfer_asset(
+            asset: &AssetInfo<T::AssetId>,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: T::Balance,
+            preservation: Preservation,
+        ) -> DispatchResult {
+            match asset {
                 AssetInfo::Native => {
-                    T::Currency::transfer(&who, &escrow_account, escrow.amount, ExistenceRequirement::AllowDeath)?;
+                    let existence = match preservation {
+                        Preservation::Expendable => ExistenceRequirement::AllowDeath,
+                        Preservation::Protect | Preservation::Preserve => ExistenceRequirement::KeepAlive,
+                    };
+                    T::Currency::transfer(from, to, amount, existence)?;
                 },
                 AssetInfo::Asset(asset_id) => {
-                    T::Assets::transfer(*asset_id, &who, &escrow_account, escrow.amount, Preservation::Expendable)?;
+                    T::Assets::transfer(*asset_id, from, to, amount, preservation)?;
                 },
                 AssetInfo::Stablecoin { asset_id, .. } => {
                     // Day 5: Enhanced stablecoin handling with precision
-                    T::Assets::transfer(*asset_id, &who, &escrow_account, escrow.amount, Preservation::Expendable)?;
+                    T::Assets::transfer(*asset_id, from, to, amount, preservation)?;
                 },
-                AssetInfo::Nft { collection_id, item_id, .. } => {
+                AssetInfo::Nft { collection_id, .. } => {
                     // Day 5: NFT support implementation
                     // Note: This requires NFTs pallet integration in runtime
                     // For now, we'll implement the basic structure
-                    T::Assets::transfer(*collection_id, &who, &escrow_account, escrow.amount, Preservation::Expendable)?;
+                    T::Assets::transfer(*collection_id, from, to, amount, preservation)?;
+                },
+                AssetInfo::Bridged(asset_id) => {
+                    // Burn-and-mint keeps total supply unchanged, like the bridge itself
+                    ensure!(
+                        T::Assets::reducible_balance(*asset_id, from, preservation, Fortitude::Polite) >= amount,
+                        TokenError::FundsUnavailable
+                    );
+                    T::Assets::burn_from(*asset_id, from, amount, Precision::Exact, Fortitude::Polite)?;
+                    T::Assets::mint_into(*asset_id, to, amount)?;
                 },
             }
+            Ok(())
+        }
 
-            // Update escrow state
-            escrow.state = EscrowState::Active;
-            escrow.updated_at = current_block;
-            Escrows::<T>::insert(&escrow_id, &escrow);
+        /// Decode the destination location of an XCM route, converted to the latest version
+        pub(crate) fn route_destination(route: &XcmRoute) -> Result<MultiLocation, DispatchError> {
+            VersionedMultiLocation::decode_all(&mut &route.destination[..])
+                .ok()
+                .and_then(|versioned| MultiLocation::try_from(versioned).ok())
+                .ok_or_else(|| Error::<T>::InvalidXcmRoute.into())
+        }
 
-            // Emit event
-            Self::deposit_event(Event::EscrowFunded {
-                escrow_id,
-                funder: who,
-            });
+        /// Preservation applied when funding an escrow with `asset`
+        pub fn preservation_for(asset: &AssetInfo<T::AssetId>) -> Preservation {
+            T::PreservationPolicy::preservation_for(asset)
+        }
 
+        /// Check that a route decodes to a sibling parachain location
+        pub fn validate_xcm_route(route: &XcmRoute) -> DispatchResult {
+            let dest = Self::route_destination(route)?;
+            ensure!(
+                dest.parents == 1 && matches!(dest.first_interior(), Some(Junction::Parachain(_))),
+                Error::<T>::InvalidXcmRoute
+            );
             Ok(())
         }
 
-        /// Complete an escrow by providing the secret
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::complete_escrow())]
-        pub fn complete_escrow(
-            origin: OriginFor<T>,
-            escrow_id: T::EscrowId,
-            secret: Vec<u8>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            
-            // Check emergency pause
-            ensure!(!Self::emergency_paused(), Error::<T>::EmergencyPaused);
-
-            // Get and validate escrow
-            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
-            ensure!(escrow.state == EscrowState::Active, Error::<T>::InvalidEscrowState);
-            ensure!(escrow.beneficiary == who, Error::<T>::NotBeneficiary);
-
-            let current_block = frame_system::Pallet::<T>::block_number();
-            ensure!(current_block < escrow.timelock, Error::<T>::TimelockExpired);
+        /// XCM location of an escrowed asset, `None` if it can't be routed
+        pub(crate) fn asset_location(asset: &AssetInfo<T::AssetId>) -> Option<MultiLocation> {
+            match asset {
+                AssetInfo::Native => Some(MultiLocation::here()),
+                AssetInfo::Asset(asset_id) | AssetInfo::Stablecoin { asset_id, .. } | AssetInfo::Bridged(asset_id) => {
+                    T::AssetIdToMultiLocation::convert(*asset_id)
+                },
+                AssetInfo::Nft { .. } => None,
+            }
+        }
 
-            // Verify secret against hashlock
-            let secret_hash = sp_core::hashing::sha2_256(&secret);
-            ensure!(secret_hash.to_vec() == escrow.hashlock.to_vec(), Error::<T>::IncorrectSecret);
+        /// Send `amount` of the escrowed funds to `recipient` on the route's destination chain
+        ///
+        /// The escrow account withdraws the asset and deposits it as a reserve transfer to
+        /// `destination`, where it is credited to the recipient's account.
+        pub(crate) fn route_via_xcm(
+            escrow_id: &T::EscrowId,
+            escrow: &Escrow<T>,
+            route: &XcmRoute,
+            recipient: &T::AccountId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            let dest = Self::route_destination(route)?;
+            let asset_location = Self::asset_location(&escrow.asset).ok_or(Error::<T>::AssetNotSupported)?;
+            let asset: MultiAsset = (asset_location, amount.saturated_into::<u128>()).into();
+            let fees = asset
+                .clone()
+                .reanchored(&dest, T::UniversalLocation::get())
+                .map_err(|_| Error::<T>::InvalidXcmRoute)?;
+            let beneficiary = T::AccountIdToMultiLocation::convert(recipient.clone());
 
-            // Transfer funds to beneficiary
-            let escrow_account = Self::escrow_account(&escrow_id);
-            
-            match &escrow.asset {
-                AssetInfo::Native => {
-                    T::Currency::transfer(&escrow_account, &who, escrow.amount, ExistenceRequirement::AllowDeath)?;
-                },
-                AssetInfo::Asset(asset_id) => {
-                    T::Assets::transfer(*asset_id, &escrow_account, &who, escrow.amount, Preservation::Expendable)?;
-                },
-                AssetInfo::Stablecoin { asset_id, .. } => {
-                    // Day 5: Enhanced stablecoin completion
-                    T::Assets::transfer(*asset_id, &escrow_account, &who, escrow.amount, Preservation::Expendable)?;
-                },
-                AssetInfo::Nft { collection_id, item_id, .. } => {
-                    // Day 5: NFT completion logic
-                    T::Assets::transfer(*collection_id, &escrow_account, &who, escrow.amount, Preservation::Expendable)?;
+            let message: Xcm<<T as frame_system::Config>::RuntimeCall> = Xcm(sp_std::vec![
+                Instruction::WithdrawAsset(asset.clone().into()),
+                Instruction::DepositReserveAsset {
+                    assets: MultiAssetFilter::Wild(WildMultiAsset::AllCounted(1)),
+                    dest,
+                    xcm: Xcm(sp_std::vec![
+                        Instruction::BuyExecution { fees, weight_limit: WeightLimit::Unlimited },
+                        Instruction::DepositAsset {
+                            assets: MultiAssetFilter::Wild(WildMultiAsset::AllCounted(1)),
+                            beneficiary,
+                        },
+                    ]),
                 },
-            }
+            ]);
+            let hash = message.using_encoded(sp_core::hashing::blake2_256);
+            let origin = T::AccountIdToMultiLocation::convert(Self::escrow_account(escrow_id));
 
-            // Update escrow state
-            escrow.state = EscrowState::Completed;
-            escrow.updated_at = current_block;
-            Escrows::<T>::insert(&escrow_id, &escrow);
+            T::XcmExecutor::execute_xcm(origin, message, hash, T::XcmWeightLimit::get())
+                .ensure_complete()
+                .map_err(|_| Error::<T>::XcmExecutionFailed)?;
 
-            // Emit event
-            Self::deposit_event(Event::EscrowCompleted {
-                escrow_id,
-                beneficiary: who,
-                secret,
+            Self::deposit_event(Event::XcmTransferInitiated {
+                escrow_id: *escrow_id,
+                destination: dest,
+                asset,
+                beneficiary: recipient.clone(),
+                amount,
+                message_hash: hash,
             });
 
             Ok(())
         }
 
-        /// Cancel an expired or invalid escrow
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::cancel_escrow())]
-        pub fn cancel_escrow(
-            origin: OriginFor<T>,
-            escrow_id: T::EscrowId,
-            reason: Vec<u8>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+        /// Extra weight a completion costs when the escrow pays out over XCM
+        ///
+        /// Calls that may complete routed escrows declare this for each completion up front and
+        /// refund it for each one that settles locally.
+        pub fn xcm_completion_overhead() -> Weight {
+            T::WeightInfo::complete_escrow_xcm(MAX_SECRET_SIZE)
+                .saturating_sub(T::WeightInfo::complete_escrow(MAX_SECRET_SIZE))
+                .saturating_add(T::XcmWeightLimit::get())
+        }
 
-            // Get and validate escrow
-            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+        /// Whether completing the escrow would send its payout over XCM
+        pub(crate) fn routed_over_xcm(escrow_id: &T::EscrowId) -> bool {
+            Self::escrows(escrow_id).map_or(false, |escrow| escrow.xcm_route.is_some())
+        }
+
+        /// Return the funds held by an active escrow to its creator
+        ///
+        /// Every refund goes through here, so it is refused once the secret has been revealed on
+        /// the counterparty chain: the taker already claimed the other leg there.
+        pub(crate) fn refund_creator(escrow_id: &T::EscrowId, escrow: &Escrow<T>) -> DispatchResult {
             ensure!(
-                escrow.state == EscrowState::Active || escrow.state == EscrowState::Created,
-                Error::<T>::InvalidEscrowState
+                !T::CrossChainProof::revealed_elsewhere(escrow_id, &escrow.hashlock),
+                Error::<T>::RevealedElsewhere
             );
+            Self::release_funds(escrow_id, escrow, &escrow.creator, Self::funding_total(escrow))
+        }
 
-            let current_block = frame_system::Pallet::<T>::block_number();
-            
-            // Check permissions: creator can cancel anytime, others only after timelock
-            if who != escrow.creator {
-                ensure!(current_block >= escrow.timelock, Error::<T>::InvalidTimelock);
-            }
+        /// Amount an escrow is funded with: the escrowed amount plus any relayer reward
+        pub(crate) fn funding_total(escrow: &Escrow<T>) -> T::Balance {
+            escrow.amount.saturating_add(escrow.options.relayer_reward.unwrap_or_else(Zero::zero))
+        }
 
-            // Refund if escrow is active
-            if escrow.state == EscrowState::Active {
-                let escrow_account = Self::escrow_account(&escrow_id);
-                
-                match &escrow.asset {
-                    AssetInfo::Native => {
-                        T::Currency::transfer(&escrow_account, &escrow.creator, escrow.amount, ExistenceRequirement::AllowDeath)?;
-                    },
-                    AssetInfo::Asset(asset_id) => {
-                        T::Assets::transfer(*asset_id, &escrow_account, &escrow.creator, escrow.amount, Preservation::Expendable)?;
-                    },
-                    AssetInfo::Stablecoin { asset_id, .. } => {
-                        // Day 5: Enhanced stablecoin refund
-                        T::Assets::transfer(*asset_id, &escrow_account, &escrow.creator, escrow.amount, Preservation::Expendable)?;
-                    },
-                    AssetInfo::Nft { collection_id, item_id, .. } => {
-                        // Day 5: NFT refund logic
-                        T::Assets::transfer(*collection_id, &escrow_account, &escrow.creator, escrow.amount, Preservation::Expendable)?;
-                    },
-                }
+        /// Pay `amount` of an escrow's locked funds out to `dest`
+        ///
+        /// Reserve-mode escrows repatriate from the funder's named reserve, which simply
+        /// unreserves when `dest` is the funder.
+        pub(crate) fn release_funds(
+            escrow_id: &T::EscrowId,
+            escrow: &Escrow<T>,
+            dest: &T::AccountId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            if !escrow.options.reserve_native {
+                let escrow_account = Self::escrow_account(escrow_id);
+                return Self::transfer_asset(&escrow.asset, &escrow_account, dest, amount, Preservation::Expendable)
             }
+            let funder = escrow.funder.as_ref().ok_or(Error::<T>::InvalidEscrowState)?;
+            let missing = T::Currency::repatriate_reserved_named(
+                &Self::reserve_id(escrow_id),
+                funder,
+                dest,
+                amount,
+                BalanceStatus::Free,
+            )?;
+            ensure!(missing.is_zero(), Error::<T>::InsufficientBalance);
+            Ok(())
+        }
 
-            // Update escrow state
-            escrow.state = EscrowState::Cancelled;
-            escrow.updated_at = current_block;
-            Escrows::<T>::insert(&escrow_id, &escrow);
+        /// Named reserve identifier for a reserve-mode escrow
+        pub fn reserve_id(escrow_id: &T::EscrowId) -> [u8; 8] {
+            (T::PalletId::get(), escrow_id).using_encoded(sp_core::hashing::blake2_64)
+        }
 
-            // Emit event
-            Self::deposit_event(Event::EscrowCancelled {
-                escrow_id,
-                canceller: who,
-                reason,
-            });
+        /// Refund an expired active escrow, forfeit any bond and mark it cancelled
+        pub(crate) fn refund_expired(
+            escrow_id: &T::EscrowId,
+            escrow: &mut Escrow<T>,
+            current_block: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            Self::refund_creator(escrow_id, escrow)?;
+            Self::settle_bond(escrow_id, escrow, true)?;
 
+            Self::set_state(escrow_id, escrow, EscrowState::Cancelled);
+            escrow.updated_at = current_block;
+            Escrows::<T>::insert(escrow_id, &*escrow);
             Ok(())
         }
 
-        /// Emergency pause for security
-        #[pallet::call_index(4)]
-        #[pallet::weight(T::WeightInfo::emergency_pause())]
-        pub fn emergency_pause(origin: OriginFor<T>) -> DispatchResult {
-            // Try signed first, then root
-            let who = if let Ok(signed) = ensure_signed(origin.clone()) {
-                signed
-            } else {
-                ensure_root(origin)?;
-                // Get the first account from the pallet for root calls
-                T::PalletId::get().into_account_truncating()
+        /// Release a posted bond, or pay it to the creator when `forfeit` is set
+        pub(crate) fn settle_bond(escrow_id: &T::EscrowId, escrow: &mut Escrow<T>, forfeit: bool) -> DispatchResult {
+            let amount = match escrow.options.bond {
+                Some(bond) if escrow.bond_posted => bond,
+                _ => return Ok(()),
             };
+            escrow.bond_posted = false;
 
-            EmergencyPaused::<T>::put(true);
-
-            Self::deposit_event(Event::EmergencyPauseActivated {
-                activator: who,
-            });
+            if forfeit {
+                T::Currency::repatriate_reserved(&escrow.beneficiary, &escrow.creator, amount, BalanceStatus::Free)?;
+                Self::deposit_event(Event::BondForfeited {
+                    escrow_id: *escrow_id,
+                    creator: escrow.creator.clone(),
+                    amount,
+                });
+            } else {
+                T::Currency::unreserve(&escrow.beneficiary, amount);
+                Self::deposit_event(Event::BondReturned {
+                    escrow_id: *escrow_id,
+                    beneficiary: escrow.beneficiary.clone(),
+                    amount,
+                });
+            }
 
             Ok(())
         }
 
-        /// Deactivate emergency pause
-        #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::emergency_unpause())]
-        pub fn emergency_unpause(origin: OriginFor<T>) -> DispatchResult {
-            // Allow either signed accounts or root
-            let who = if let Ok(who) = ensure_signed(origin.clone()) {
-                who
-            } else {
-                ensure_root(origin)?;
-                // For root calls, we'll use a dummy account ID (this won't be stored anyway)
-                Self::account_id()
-            };
+        /// Get escrow by ID
+        pub fn get_escrow(escrow_id: &T::EscrowId) -> Option<Escrow<T>> {
+            Self::escrows(escrow_id)
+        }
 
-            EmergencyPaused::<T>::put(false);
+        /// Check whether any operation is paused
+        pub fn emergency_paused() -> bool {
+            Self::pause_flags().any()
+        }
 
-            Self::deposit_event(Event::EmergencyPauseDeactivated {
-                deactivator: who,
-            });
+        /// Pause state, counters and native TVL in one read for status pages
+        pub fn pallet_summary() -> PalletSummary<T::EscrowId> {
+            PalletSummary {
+                paused: Self::emergency_paused(),
+                total_active: Self::total_active(),
+                total_completed: Self::total_completed(),
+                tvl_native: Self::total_locked(AssetType::Native),
+                next_id: Self::next_escrow_id(),
+            }
+        }
 
-            Ok(())
+        /// Secret a completed escrow was redeemed with, if it was retained
+        pub fn get_revealed_secret(escrow_id: &T::EscrowId) -> Option<Vec<u8>> {
+            RevealedSecrets::<T>::get(escrow_id).map(|secret| secret.into_inner())
         }
-    }
 
-    // Helper methods
-    impl<T: Config> Pallet<T> {
-        /// Get the pallet's account ID
-        pub fn account_id() -> T::AccountId {
-            T::PalletId::get().into_account_truncating()
+        /// Get escrows for an account, bounded by `MaxEnumerated`
+        pub fn get_account_escrows(account: &T::AccountId) -> Vec<T::EscrowId> {
+            Self::get_account_escrows_paginated(account, 0, T::MaxEnumerated::get())
         }
 
-        /// Generate deterministic account ID for escrow
-        pub fn escrow_account(escrow_id: &T::EscrowId) -> T::AccountId {
-            T::PalletId::get().into_sub_account_truncating(escrow_id)
+        /// Up to `limit` (capped at `MaxEnumerated`) of an account's escrows, skipping `offset`
+        pub fn get_account_escrows_paginated(account: &T::AccountId, offset: u32, limit: u32) -> Vec<T::EscrowId> {
+            let limit = limit.min(T::MaxEnumerated::get()) as usize;
+            Self::account_escrows(account).into_iter().skip(offset as usize).take(limit).collect()
         }
 
-        /// Get escrow by ID
-        pub fn get_escrow(escrow_id: &T::EscrowId) -> Option<Escrow<T>> {
-            Self::escrows(escrow_id)
+        /// Get escrow IDs currently in the given state, bounded by `MaxEnumerated`
+        pub fn escrows_in_state(state: EscrowState) -> Vec<T::EscrowId> {
+            EscrowsByState::<T>::iter_key_prefix(state)
+                .take(T::MaxEnumerated::get() as usize)
+                .collect()
         }
 
-        /// Get escrows for an account
-        pub fn get_account_escrows(account: &T::AccountId) -> Vec<T::EscrowId> {
-            Self::account_escrows(account).into_inner()
+        /// Escrows `taker` can complete right now: active, unfrozen and before the deadline
+        pub fn completable_escrows_for_taker(taker: &T::AccountId) -> Vec<T::EscrowId> {
+            let current_block = frame_system::Pallet::<T>::block_number();
+            Self::beneficiary_escrows(taker)
+                .into_iter()
+                .filter(|id| {
+                    Self::escrows(id).map_or(false, |escrow| {
+                        escrow.state == EscrowState::Active &&
+                            !escrow.frozen &&
+                            !Self::deadline_passed(&escrow, current_block)
+                    })
+                })
+                .collect()
+        }
+
+        /// Current Unix time in seconds; `TimeProvider` reports milliseconds
+        pub fn now_unix_secs() -> u64 {
+            T::TimeProvider::now().saturated_into::<u64>() / 1000
+        }
+
+        /// Whether the block timelock or, if set, the Unix deadline has passed
+        pub(crate) fn deadline_passed(escrow: &Escrow<T>, current_block: BlockNumberFor<T>) -> bool {
+            current_block >= escrow.timelock ||
+                escrow
+                    .options
+                    .timelock_timestamp
+                    .map_or(false, |deadline| Self::now_unix_secs() >= deadline)
+        }
+
+        /// Whether an expired escrow may be refunded: `CancelSafetyGap` blocks after the
+        /// timelock, or `CancelSafetyGapSecs` after the Unix deadline
+        pub(crate) fn refund_open(escrow: &Escrow<T>, current_block: BlockNumberFor<T>) -> bool {
+            current_block >= escrow.timelock.saturating_add(T::CancelSafetyGap::get()) ||
+                escrow.options.timelock_timestamp.map_or(false, |deadline| {
+                    Self::now_unix_secs() >= deadline.saturating_add(T::CancelSafetyGapSecs::get())
+                })
+        }
+
+        /// A threshold maker's creator key alone can't cancel before the refund window opens;
+        /// `approved` is set once `approve_cancel` has collected enough signers
+        pub(crate) fn ensure_maker_approval(
+            escrow: &Escrow<T>,
+            current_block: BlockNumberFor<T>,
+            approved: bool,
+        ) -> DispatchResult {
+            if escrow.options.maker_threshold.is_some() && !approved {
+                ensure!(Self::refund_open(escrow, current_block), Error::<T>::MakerApprovalRequired);
+            }
+            Ok(())
+        }
+
+        /// Whether an escrow's funds are actually held, whatever its recorded state says
+        ///
+        /// Compares the escrow account, or the funder's named reserve in reserve mode, against
+        /// the escrowed amount plus any relayer reward, so accounting drift shows up even while
+        /// the escrow is `Active`.
+        pub fn is_fully_funded(escrow_id: &T::EscrowId) -> bool {
+            Self::escrows(escrow_id).map_or(false, |escrow| {
+                Self::escrow_holdings(escrow_id, &escrow) >= Self::funding_total(&escrow).saturated_into::<u128>()
+            })
+        }
+
+        /// Whether no live escrow uses `hashlock`
+        ///
+        /// Completed and cancelled escrows free their hashlock whichever path settled them.
+        pub fn is_secret_hash_available(hashlock: &[u8; 32]) -> bool {
+            let hashlock: BoundedVec<u8, ConstU32<MAX_HASHLOCK_SIZE>> =
+                BoundedVec::truncate_from(hashlock.to_vec());
+            EscrowsByHashlock::<T>::iter_key_prefix(&hashlock).next().is_none()
         }
 
         /// Check if timelock has expired
         pub fn is_timelock_expired(escrow_id: &T::EscrowId) -> bool {
             if let Some(escrow) = Self::escrows(escrow_id) {
                 let current_block = frame_system::Pallet::<T>::block_number();
-                current_block >= escrow.timelock
+                Self::deadline_passed(&escrow, current_block)
             } else {
                 false
             }
         }
 
+        /// Aggregate status of an escrow for clients
+        pub fn escrow_status(escrow_id: &T::EscrowId) -> EscrowStatus<BlockNumberFor<T>> {
+            let escrow = match Self::escrows(escrow_id) {
+                Some(escrow) => escrow,
+                None => return EscrowStatus::NotFound,
+            };
+            let current_block = frame_system::Pallet::<T>::block_number();
+
+            match escrow.state {
+                EscrowState::Created | EscrowState::Accepted => EscrowStatus::AwaitingFunding,
+                EscrowState::Active if !Self::deadline_passed(&escrow, current_block) => {
+                    EscrowStatus::ActiveCompletable { remaining: escrow.timelock.saturating_sub(current_block) }
+                },
+                EscrowState::Active => EscrowStatus::ActiveExpired,
+                EscrowState::Completed => EscrowStatus::Completed,
+                EscrowState::Cancelled => EscrowStatus::Cancelled,
+                EscrowState::Paused => EscrowStatus::Paused,
+                EscrowState::Disputable => EscrowStatus::Disputable {
+                    finalize_at: Self::refund_holds(escrow_id).unwrap_or(current_block),
+                },
+            }
+        }
+
+        /// Encode `(bytes32 secret, bytes32 orderHash)` using the Ethereum ABI layout
+        ///
+        /// Completion passes the escrow's `eth_order_hash` as the order hash when one is linked,
+        /// and otherwise its hashlock, since the Ethereum contract also keys orders by their
+        /// secret hash. Values shorter than 32 bytes are right-padded with zeros as
+        /// `bytes32` requires; `None` is returned if either value exceeds 32 bytes.
+        pub fn eth_claim_payload(secret: &[u8], order_hash: &[u8]) -> Option<Vec<u8>> {
+            if secret.len() > 32 || order_hash.len() > 32 {
+                return None;
+            }
+
+            let mut payload = sp_std::vec![0u8; 64];
+            payload[..secret.len()].copy_from_slice(secret);
+            payload[32..32 + order_hash.len()].copy_from_slice(order_hash);
+            Some(payload)
+        }
+
+        /// Strip an algorithm tag from a tagged hashlock; untagged ones use `DefaultHashAlgorithm`
+        pub(crate) fn split_hashlock(mut hashlock: Vec<u8>) -> Result<(HashAlgorithm, Vec<u8>), DispatchError> {
+            if hashlock.len() != MAX_HASHLOCK_SIZE as usize + 1 {
+                return Ok((T::DefaultHashAlgorithm::get(), hashlock))
+            }
+            let algorithm = HashAlgorithm::from_tag(hashlock[0]).ok_or(Error::<T>::InvalidHashlock)?;
+            hashlock.remove(0);
+            Ok((algorithm, hashlock))
+        }
+
         /// Verify hashlock secret
         pub fn verify_secret(escrow_id: &T::EscrowId, secret: &[u8]) -> bool {
             if let Some(escrow) = Self::escrows(escrow_id) {
-                let secret_hash = sp_core::hashing::sha2_256(secret);
+                let secret_hash = escrow.hash_algorithm.hash_rounds(secret, escrow.options.hash_rounds);
                 secret_hash.to_vec() == escrow.hashlock.to_vec()
             } else {
                 false
             }
         }
+
+        /// Append to the recent events buffer, evicting the oldest entry when full
+        pub(crate) fn record_event(escrow_id: &T::EscrowId, kind: EscrowEventKind) {
+            RecentEvents::<T>::mutate(|events| {
+                if events.len() as u32 >= MAX_RECENT_EVENTS {
+                    events.remove(0);
+                }
+                let _ = events.try_push(EscrowEventSummary {
+                    escrow_id: *escrow_id,
+                    kind,
+                    block: frame_system::Pallet::<T>::block_number(),
+                });
+            });
+        }
+
+        /// Most recent escrow lifecycle events, oldest first
+        pub fn recent_events() -> Vec<EscrowEventSummaryOf<T>> {
+            RecentEvents::<T>::get().into_inner()
+        }
+
+        /// Scale an amount to `CANONICAL_DECIMALS` so both chains compare like for like
+        ///
+        /// Assets without known decimals (plain fungibles and NFTs) are returned unscaled.
+        pub fn normalized_amount(asset: &AssetInfo<T::AssetId>, amount: T::Balance) -> u128 {
+            let raw: u128 = amount.saturated_into();
+            match asset.decimals() {
+                Some(decimals) if decimals < CANONICAL_DECIMALS =>
+                    raw.saturating_mul(10u128.saturating_pow((CANONICAL_DECIMALS - decimals) as u32)),
+                Some(decimals) => raw / 10u128.saturating_pow((decimals - CANONICAL_DECIMALS) as u32),
+                None => raw,
+            }
+        }
+
+        /// Protocol fee on completing an escrow of `amount`; NFTs can't be split and pay none
+        pub fn protocol_fee(asset: &AssetInfo<T::AssetId>, amount: T::Balance) -> T::Balance {
+            if asset.asset_type() == AssetType::Nft {
+                return Zero::zero()
+            }
+            let raw: u128 = amount.saturated_into();
+            (raw.saturating_mul(T::ProtocolFeeBps::get() as u128) / 10_000).saturated_into()
+        }
+
+        /// Balance of the escrowed asset held for an escrow, in its account or funder's reserve
+        pub(crate) fn escrow_holdings(escrow_id: &T::EscrowId, escrow: &Escrow<T>) -> u128 {
+            if escrow.options.reserve_native {
+                return escrow.funder.as_ref().map_or(0, |funder| {
+                    T::Currency::reserved_balance_named(&Self::reserve_id(escrow_id), funder).saturated_into()
+                })
+            }
+            Self::account_holdings(&Self::escrow_account(escrow_id), &escrow.asset)
+        }
+
+        /// Free balance of `asset` held by `account`
+        pub(crate) fn account_holdings(account: &T::AccountId, asset: &AssetInfo<T::AssetId>) -> u128 {
+            let held = match asset {
+                AssetInfo::Native => T::Currency::free_balance(account),
+                AssetInfo::Asset(asset_id) | AssetInfo::Stablecoin { asset_id, .. } | AssetInfo::Bridged(asset_id) =>
+                    T::Assets::balance(*asset_id, account),
+                AssetInfo::Nft { collection_id, .. } => T::Assets::balance(*collection_id, account),
+            };
+            held.saturated_into()
+        }
+
+        /// Allowed `(min, max)` timelock durations for `asset`, preferring its type's override
+        pub fn timelock_bounds(asset: &AssetInfo<T::AssetId>) -> (BlockNumberFor<T>, BlockNumberFor<T>) {
+            Self::asset_timelock_bounds(asset.asset_type())
+                .unwrap_or((T::MinTimelockDuration::get(), T::MaxTimelockDuration::get()))
+        }
+
+        /// Reject a timelock duration outside `asset`'s bounds, naming the side it fell off
+        pub(crate) fn ensure_timelock_in_bounds(
+            asset: &AssetInfo<T::AssetId>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let (min_timelock, max_timelock) = Self::timelock_bounds(asset);
+            ensure!(duration >= min_timelock, Error::<T>::TimelockTooShort);
+            ensure!(duration <= max_timelock, Error::<T>::TimelockTooLong);
+            Ok(())
+        }
+
+        /// Smallest balance an account of the escrowed asset may hold
+        pub(crate) fn minimum_balance(asset: &AssetInfo<T::AssetId>) -> T::Balance {
+            match asset {
+                AssetInfo::Native => T::Currency::minimum_balance(),
+                AssetInfo::Asset(asset_id) |
+                AssetInfo::Stablecoin { asset_id, .. } |
+                AssetInfo::Bridged(asset_id) |
+                AssetInfo::Nft { collection_id: asset_id, .. } => T::Assets::minimum_balance(*asset_id),
+            }
+        }
+
+        /// Per-asset totals owed by active escrows against what their accounts hold
+        ///
+        /// Covers at most `MaxGlobalActiveEscrows` escrows, which bounds every active one.
+        pub fn solvency_report() -> Vec<AssetSolvency<T::AssetId>> {
+            let mut report: Vec<AssetSolvency<T::AssetId>> = Vec::new();
+            let active = EscrowsByState::<T>::iter_key_prefix(EscrowState::Active)
+                .take(T::MaxGlobalActiveEscrows::get() as usize)
+                .filter_map(|id| Self::escrows(&id).map(|escrow| (id, escrow)));
+            for (escrow_id, escrow) in active {
+                let key = escrow.asset.key();
+                let expected: u128 = Self::funding_total(&escrow).saturated_into();
+                let actual = Self::escrow_holdings(&escrow_id, &escrow);
+                match report.iter_mut().find(|entry| entry.asset == key) {
+                    Some(entry) => {
+                        entry.expected = entry.expected.saturating_add(expected);
+                        entry.actual = entry.actual.saturating_add(actual);
+                    },
+                    None => report.push(AssetSolvency { asset: key, expected, actual }),
+                }
+            }
+            report
+        }
+
+        /// Check that escrow accounts cover every active escrow, returning any shortfalls
+        pub fn reconcile() -> Result<(), Vec<AssetSolvency<T::AssetId>>> {
+            let shortfalls: Vec<_> = Self::solvency_report().into_iter().filter(|entry| !entry.is_solvent()).collect();
+            if shortfalls.is_empty() {
+                Ok(())
+            } else {
+                Err(shortfalls)
+            }
+        }
+
+        /// Quote what the pallet takes from a creator for creating an escrow, without touching state
+        ///
+        /// Creation is free today: principal moves only on funding, and any bond is posted by the
+        /// beneficiary. Asset and amount are taken so the quote stays stable as surcharges are added.
+        pub fn estimate_create_fee(_asset: &AssetInfo<T::AssetId>, _amount: T::Balance) -> T::Balance {
+            Zero::zero()
+        }
+
+        /// Toggle the operator freeze on a live escrow
+        pub(crate) fn set_frozen(escrow_id: T::EscrowId, frozen: bool) -> DispatchResult {
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(
+                matches!(
+                    escrow.state,
+                    EscrowState::Active | EscrowState::Created | EscrowState::Accepted | EscrowState::Disputable
+                ),
+                Error::<T>::InvalidEscrowState
+            );
+
+            escrow.frozen = frozen;
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            if frozen {
+                Self::deposit_event(Event::EscrowFrozen { escrow_id });
+            } else {
+                Self::deposit_event(Event::EscrowUnfrozen { escrow_id });
+            }
+
+            Ok(())
+        }
+
+        /// Move an escrow between `Active` and `Paused`
+        pub(crate) fn set_paused(escrow_id: T::EscrowId, paused: bool) -> DispatchResult {
+            let mut escrow = Self::escrows(&escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+            let (from, to) = if paused {
+                (EscrowState::Active, EscrowState::Paused)
+            } else {
+                (EscrowState::Paused, EscrowState::Active)
+            };
+            ensure!(escrow.state == from, Error::<T>::InvalidEscrowState);
+
+            Self::set_state(&escrow_id, &mut escrow, to);
+            escrow.updated_at = frame_system::Pallet::<T>::block_number();
+            Escrows::<T>::insert(&escrow_id, &escrow);
+
+            if paused {
+                Self::deposit_event(Event::EscrowPaused { escrow_id });
+            } else {
+                Self::deposit_event(Event::EscrowResumed { escrow_id });
+            }
+
+            Ok(())
+        }
+
+        /// Page through all escrows for indexers
+        ///
+        /// Returns up to `limit` escrows (capped at `MaxEnumerated`) stored after `start_after`,
+        /// and the cursor for the next page, or `None` once every escrow has been returned.
+        /// Pages follow storage order, which is stable but not sorted by ID.
+        pub fn escrows_paginated(
+            start_after: Option<T::EscrowId>,
+            limit: u32,
+        ) -> (Vec<(T::EscrowId, Escrow<T>)>, Option<T::EscrowId>) {
+            let limit = limit.min(T::MaxEnumerated::get()) as usize;
+            let iter = match start_after {
+                Some(id) => Escrows::<T>::iter_from(Escrows::<T>::hashed_key_for(id)),
+                None => Escrows::<T>::iter(),
+            };
+
+            // Read one extra entry to learn whether another page exists
+            let mut page: Vec<_> = iter.take(limit.saturating_add(1)).collect();
+            let cursor = if page.len() > limit {
+                page.truncate(limit);
+                page.last().map(|(id, _)| *id)
+            } else {
+                None
+            };
+            (page, cursor)
+        }
+
+        /// Move an expired escrow with a dispute window into `Disputable` instead of refunding
+        ///
+        /// Returns whether the escrow is now held.
+        pub(crate) fn hold_for_dispute(
+            escrow_id: &T::EscrowId,
+            escrow: &mut Escrow<T>,
+            current_block: BlockNumberFor<T>,
+        ) -> bool {
+            let window = match escrow.options.dispute_window {
+                Some(window) => window,
+                None => return false,
+            };
+            let finalize_at = current_block.saturating_add(window);
+
+            RefundHolds::<T>::insert(escrow_id, finalize_at);
+            Self::set_state(escrow_id, escrow, EscrowState::Disputable);
+            escrow.updated_at = current_block;
+            Escrows::<T>::insert(escrow_id, &*escrow);
+
+            Self::deposit_event(Event::RefundHeld { escrow_id: *escrow_id, finalize_at });
+            true
+        }
     }
 }
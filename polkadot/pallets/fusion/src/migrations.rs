@@ -0,0 +1,149 @@
+//! Storage migrations for the fusion pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Storage layout of the original `lib_substrate` escrow pallet
+pub mod v0 {
+    use super::*;
+
+    /// Legacy escrows keyed by secret hash: `(creator, timelock, amount)`
+    #[frame_support::storage_alias]
+    pub type Escrows<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        [u8; 32],
+        (<T as frame_system::Config>::AccountId, BlockNumberFor<T>, u32),
+        OptionQuery,
+    >;
+}
+
+/// Record storage version 1 on chains that predate storage versioning
+///
+/// Nothing is migrated; this only sets the baseline later schema migrations build on.
+pub struct InitializeStorageVersion<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for InitializeStorageVersion<T> {
+    fn on_runtime_upgrade() -> Weight {
+        StorageVersion::new(1).put::<Pallet<T>>();
+        T::DbWeight::get().writes(1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not set");
+        Ok(())
+    }
+}
+
+/// Metadata attached to escrows carried over from the legacy pallet
+pub const LEGACY_METADATA: &[u8] = b"Migrated from legacy escrow";
+
+/// Move legacy `lib_substrate` escrows into the current `Escrow` layout
+///
+/// Legacy escrows never held funds, so they come across as `Created` native escrows with
+/// fresh IDs. The legacy format has no beneficiary; the creator is recorded as a placeholder
+/// and can point it elsewhere with `reassign_beneficiary`.
+pub struct MigrateLegacyEscrows<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateLegacyEscrows<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= 1 {
+            return T::DbWeight::get().reads(1)
+        }
+
+        // The legacy map shares the `Escrows` prefix with live escrows. Only keys that decode
+        // as a 32-byte secret hash are legacy, and each is taken only if its value decodes in
+        // the legacy layout, so current escrows are never touched. Keys are collected first so
+        // the new entries are never read back.
+        let keys: Vec<[u8; 32]> = v0::Escrows::<T>::iter_keys().collect();
+        let scanned = keys.len() as u64;
+        let legacy: Vec<_> =
+            keys.into_iter().filter_map(|key| v0::Escrows::<T>::take(key).map(|value| (key, value))).collect();
+        let current_block = frame_system::Pallet::<T>::block_number();
+        let mut next_id = Pallet::<T>::next_escrow_id();
+        let mut writes = 0u64;
+        let mut migrated_count = 0u64;
+
+        for (secret_hash, (creator, timelock, amount)) in legacy.iter().cloned() {
+            let escrow_id = next_id;
+            next_id = match next_id.checked_add(&T::EscrowId::from(1u64)) {
+                Some(id) => id,
+                None => break,
+            };
+
+            let escrow = Escrow::<T> {
+                id: escrow_id,
+                creator: creator.clone(),
+                beneficiary: creator.clone(),
+                asset: AssetInfo::Native,
+                amount: amount.into(),
+                funder: None,
+                funded_at: None,
+                hashlock: BoundedVec::truncate_from(secret_hash.to_vec()),
+                hash_algorithm: HashAlgorithm::Sha256,
+                timelock,
+                state: EscrowState::Created,
+                metadata: BoundedVec::truncate_from(LEGACY_METADATA.to_vec()),
+                xcm_route: None,
+                created_at: current_block,
+                updated_at: current_block,
+                options: Default::default(),
+                bond_posted: false,
+                frozen: false,
+            };
+
+            Escrows::<T>::insert(&escrow_id, &escrow);
+            EscrowsByState::<T>::insert(EscrowState::Created, &escrow_id, ());
+            EscrowsByHashlock::<T>::insert(&escrow.hashlock, &escrow_id, ());
+            // Accounts already at the cap keep the escrow, just unindexed
+            let _ = AccountEscrows::<T>::try_mutate(&creator, |escrows| escrows.try_push(escrow_id));
+            let _ = BeneficiaryEscrows::<T>::try_mutate(&creator, |escrows| escrows.try_push(escrow_id));
+            writes = writes.saturating_add(6);
+            migrated_count = migrated_count.saturating_add(1);
+        }
+
+        NextEscrowId::<T>::put(next_id);
+        TotalCreated::<T>::mutate(|count| *count = count.saturating_add(migrated_count));
+        StorageVersion::new(1).put::<Pallet<T>>();
+
+        let migrated = legacy.len() as u64;
+        T::DbWeight::get().reads_writes(
+            migrated.saturating_mul(3).saturating_add(scanned).saturating_add(3),
+            writes.saturating_add(migrated).saturating_add(3),
+        )
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let legacy = v0::Escrows::<T>::iter_keys().count() as u64;
+        Ok((legacy, Pallet::<T>::next_escrow_id()).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (legacy, first_id): (u64, T::EscrowId) =
+            Decode::decode(&mut &state[..]).map_err(|_| "pre_upgrade state is corrupt")?;
+
+        ensure!(Pallet::<T>::on_chain_storage_version() >= 1, "storage version not bumped");
+        ensure!(v0::Escrows::<T>::iter_keys().next().is_none(), "legacy escrows left behind");
+
+        let mut id = first_id;
+        for _ in 0..legacy {
+            let escrow = Escrows::<T>::get(&id).ok_or("migrated escrow missing")?;
+            ensure!(escrow.state == EscrowState::Created, "migrated escrow has wrong state");
+            ensure!(
+                EscrowsByState::<T>::contains_key(EscrowState::Created, &id),
+                "migrated escrow missing from state index"
+            );
+            id = id.checked_add(&T::EscrowId::from(1u64)).ok_or("escrow id overflow")?;
+        }
+        ensure!(Pallet::<T>::next_escrow_id() == id, "next escrow id out of sync");
+
+        Ok(())
+    }
+}
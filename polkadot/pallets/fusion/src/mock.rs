@@ -2,14 +2,23 @@
 
 use crate as pallet_fusion;
 use frame_support::{
-    construct_runtime, parameter_types,
-    traits::{ConstU32, Everything, Hooks},
+    construct_runtime, ord_parameter_types, parameter_types,
+    traits::{
+        schedule::{v3::Named as ScheduleNamed, DispatchTime, Period, Priority, TaskName},
+        tokens::Preservation, Bounded, ConstU32, EitherOfDiverse, Everything, Hooks,
+    },
+    weights::Weight,
     PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    testing::{TestSignature, UintAuthorityId},
+    traits::{BlakeTwo256, Convert, IdentityLookup},
+    BuildStorage, DispatchError,
+};
+use xcm::latest::{
+    Error as XcmError, ExecuteXcm, InteriorMultiLocation, Junction, Junctions, MultiAssets,
+    MultiLocation, NetworkId, Outcome, PreparedMessage, Result as XcmResult, Xcm, XcmHash,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -124,12 +133,215 @@ impl pallet_assets::Config for Test {
     type BenchmarkHelper = ();
 }
 
+parameter_types! {
+    pub static XcmExecutionFails: bool = false;
+    pub static XcmExecutedCount: u32 = 0;
+    pub static LastXcmHash: XcmHash = [0u8; 32];
+    pub static NonSufficientAssets: Vec<u32> = vec![];
+    pub static EscrowHookCalls: Vec<(&'static str, u64)> = vec![];
+    pub static ProtocolFeeBps: u32 = 0;
+    pub static MaxGlobalActiveEscrows: u32 = 1_000;
+    pub static OracleRate: Option<u128> = Some(2);
+    pub static MaxMetadataLen: u32 = 1024;
+    pub static RequireTakerAcceptance: bool = false;
+    pub static RelatedPairs: Vec<(u64, u64)> = vec![];
+    pub static RevealedEscrows: Vec<u64> = vec![];
+    pub static ScheduledTasks: Vec<(TaskName, u64)> = vec![];
+    pub static DefaultHashAlgorithm: pallet_fusion::HashAlgorithm = pallet_fusion::HashAlgorithm::Sha256;
+    pub UniversalLocation: InteriorMultiLocation =
+        Junctions::X2(Junction::GlobalConsensus(NetworkId::Polkadot), Junction::Parachain(1000));
+    pub XcmWeightLimit: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+}
+
+/// Prepared message for the mock XCM executor
+pub struct MockPrepared;
+
+impl PreparedMessage for MockPrepared {
+    fn weight_of(&self) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// XCM executor that records executions instead of moving assets
+pub struct MockXcmExecutor;
+
+impl ExecuteXcm<RuntimeCall> for MockXcmExecutor {
+    type Prepared = MockPrepared;
+
+    fn prepare(_message: Xcm<RuntimeCall>) -> Result<Self::Prepared, Xcm<RuntimeCall>> {
+        Ok(MockPrepared)
+    }
+
+    fn execute(
+        _origin: impl Into<MultiLocation>,
+        _pre: Self::Prepared,
+        id: &mut XcmHash,
+        _weight_credit: Weight,
+    ) -> Outcome {
+        if XcmExecutionFails::get() {
+            return Outcome::Error(XcmError::Unimplemented);
+        }
+        XcmExecutedCount::set(XcmExecutedCount::get() + 1);
+        LastXcmHash::set(*id);
+        Outcome::Complete(Weight::zero())
+    }
+
+    fn charge_fees(_location: impl Into<MultiLocation>, _fees: MultiAssets) -> XcmResult {
+        Ok(())
+    }
+}
+
+/// Maps test accounts onto `AccountId32` junctions
+pub struct AccountIdToMultiLocation;
+
+impl Convert<u64, MultiLocation> for AccountIdToMultiLocation {
+    fn convert(who: u64) -> MultiLocation {
+        let mut id = [0u8; 32];
+        id[..8].copy_from_slice(&who.to_le_bytes());
+        Junction::AccountId32 { network: None, id }.into()
+    }
+}
+
+/// Maps asset IDs onto `pallet_assets` general indices
+pub struct AssetIdToMultiLocation;
+
+impl Convert<u32, Option<MultiLocation>> for AssetIdToMultiLocation {
+    fn convert(asset_id: u32) -> Option<MultiLocation> {
+        Some(MultiLocation::new(
+            0,
+            Junctions::X2(Junction::PalletInstance(50), Junction::GeneralIndex(asset_id.into())),
+        ))
+    }
+}
+
+/// Keeps funders of non-sufficient assets alive, since their accounts can't exist on them alone
+pub struct SufficiencyPreservation;
+
+impl pallet_fusion::PreservationPolicy<u32> for SufficiencyPreservation {
+    fn preservation_for(asset: &pallet_fusion::AssetInfo<u32>) -> Preservation {
+        let asset_id = match asset {
+            pallet_fusion::AssetInfo::Asset(asset_id) |
+            pallet_fusion::AssetInfo::Stablecoin { asset_id, .. } |
+            pallet_fusion::AssetInfo::Bridged(asset_id) => *asset_id,
+            _ => return Preservation::Expendable,
+        };
+        if NonSufficientAssets::get().contains(&asset_id) {
+            Preservation::Preserve
+        } else {
+            Preservation::Expendable
+        }
+    }
+}
+
+/// Records lifecycle callbacks for assertions
+pub struct RecordingEscrowHooks;
+
+impl pallet_fusion::EscrowEventHandler<u64, pallet_fusion::Escrow<Test>> for RecordingEscrowHooks {
+    fn on_funded(escrow_id: &u64, _escrow: &pallet_fusion::Escrow<Test>) {
+        EscrowHookCalls::mutate(|calls| calls.push(("funded", *escrow_id)));
+    }
+
+    fn on_completed(escrow_id: &u64, _escrow: &pallet_fusion::Escrow<Test>) {
+        EscrowHookCalls::mutate(|calls| calls.push(("completed", *escrow_id)));
+    }
+
+    fn on_cancelled(escrow_id: &u64, _escrow: &pallet_fusion::Escrow<Test>) {
+        EscrowHookCalls::mutate(|calls| calls.push(("cancelled", *escrow_id)));
+    }
+}
+
+/// Records named tasks instead of dispatching them; tests dispatch due calls by hand
+pub struct MockScheduler;
+
+impl ScheduleNamed<u64, RuntimeCall, OriginCaller> for MockScheduler {
+    type Address = u32;
+
+    fn schedule_named(
+        id: TaskName,
+        when: DispatchTime<u64>,
+        _maybe_periodic: Option<Period<u64>>,
+        _priority: Priority,
+        _origin: OriginCaller,
+        _call: Bounded<RuntimeCall>,
+    ) -> Result<u32, DispatchError> {
+        let at = match when {
+            DispatchTime::At(at) => at,
+            DispatchTime::After(delay) => System::block_number() + delay,
+        };
+        ScheduledTasks::mutate(|tasks| tasks.push((id, at)));
+        Ok(0)
+    }
+
+    fn cancel_named(id: TaskName) -> Result<(), DispatchError> {
+        ScheduledTasks::mutate(|tasks| {
+            let before = tasks.len();
+            tasks.retain(|(task, _)| *task != id);
+            if tasks.len() < before { Ok(()) } else { Err(DispatchError::Unavailable) }
+        })
+    }
+
+    fn reschedule_named(_id: TaskName, _when: DispatchTime<u64>) -> Result<u64, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn next_dispatch_time(id: TaskName) -> Result<u64, DispatchError> {
+        ScheduledTasks::get()
+            .into_iter()
+            .find(|(task, _)| *task == id)
+            .map(|(_, at)| at)
+            .ok_or(DispatchError::Unavailable)
+    }
+}
+
 parameter_types! {
     pub const FusionPalletId: PalletId = PalletId(*b"py/fusio");
     pub const MaxEscrowsPerAccount: u32 = 100;
     pub const MinTimelockDuration: u64 = 10; // 10 blocks minimum
     pub const MaxTimelockDuration: u64 = 1_000_000; // ~7 days at 6 second blocks
     pub const EscrowFee: u128 = 1_000_000_000_000; // 1 DOT fee
+    pub const MinEscrowAmount: u128 = 100;
+    pub const MaxEnumerated: u32 = 100;
+    pub const EmitEthClaimPayload: bool = true;
+    pub const RetentionPeriod: u64 = 100;
+    pub const MaxLinkedReveals: u32 = 8;
+    pub const MaxSweep: u32 = 4;
+    pub const AllowAllWhenUnlisted: bool = true;
+    pub const CancelSafetyGap: u64 = 5;
+    pub const CancelSafetyGapSecs: u64 = 30;
+    pub const MaxBatchCompletions: u32 = 4;
+    pub const FeeCollector: u64 = FEE_COLLECTOR;
+    pub const ConversionPool: u64 = CONVERSION_POOL;
+}
+
+/// Oracle quoting every pair at `OracleRate` units out per unit in
+pub struct FixedRateOracle;
+
+impl pallet_fusion::PriceProvider<u32, u128> for FixedRateOracle {
+    fn convert(
+        _from: &pallet_fusion::AssetKey<u32>,
+        _to: &pallet_fusion::AssetKey<u32>,
+        amount: u128,
+    ) -> Option<u128> {
+        OracleRate::get().map(|rate| amount.saturating_mul(rate))
+    }
+}
+
+/// Flags the `(maker, taker)` pairs listed in `RelatedPairs`
+pub struct RelatedPairFilter;
+
+impl pallet_fusion::SelfDealFilter<u64> for RelatedPairFilter {
+    fn is_related(maker: &u64, taker: &u64) -> bool {
+        RelatedPairs::get().contains(&(*maker, *taker))
+    }
+}
+
+/// Reports a counterparty-chain reveal for the escrows listed in `RevealedEscrows`
+pub struct MockRevealVerifier;
+
+impl pallet_fusion::RevealVerifier<u64> for MockRevealVerifier {
+    fn revealed_elsewhere(escrow_id: &u64, _hashlock: &[u8]) -> bool {
+        RevealedEscrows::get().contains(escrow_id)
+    }
 }
 
 impl pallet_fusion::Config for Test {
@@ -142,11 +354,50 @@ impl pallet_fusion::Config for Test {
     type Currency = Balances;
     type TimeProvider = Timestamp;
     type Randomness = Randomness;
+    type XcmExecutor = MockXcmExecutor;
+    type AccountIdToMultiLocation = AccountIdToMultiLocation;
+    type AssetIdToMultiLocation = AssetIdToMultiLocation;
+    type UniversalLocation = UniversalLocation;
+    type XcmWeightLimit = XcmWeightLimit;
     type PalletId = FusionPalletId;
     type MaxEscrowsPerAccount = MaxEscrowsPerAccount;
     type MinTimelockDuration = MinTimelockDuration;
     type MaxTimelockDuration = MaxTimelockDuration;
     type EscrowFee = EscrowFee;
+    type MinEscrowAmount = MinEscrowAmount;
+    type MaxEnumerated = MaxEnumerated;
+    type EmitEthClaimPayload = EmitEthClaimPayload;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type FreezeOrigin = frame_system::EnsureRoot<u64>;
+    type PauseOrigin = EitherOfDiverse<frame_system::EnsureRoot<u64>, frame_system::EnsureSignedBy<PauseGuardian, u64>>;
+    type MaxLinkedReveals = MaxLinkedReveals;
+    type MaxMetadataLen = MaxMetadataLen;
+    type MaxSweep = MaxSweep;
+    type AllowAllWhenUnlisted = AllowAllWhenUnlisted;
+    type CancelSafetyGap = CancelSafetyGap;
+    type CancelSafetyGapSecs = CancelSafetyGapSecs;
+    type MaxBatchCompletions = MaxBatchCompletions;
+    type OnEscrowEvent = RecordingEscrowHooks;
+    type ProtocolFeeBps = ProtocolFeeBps;
+    type FeeCollector = FeeCollector;
+    type FeeAdminOrigin = frame_system::EnsureRoot<u64>;
+    type PriceOracle = FixedRateOracle;
+    type AntiSelfDeal = RelatedPairFilter;
+    type ConversionPool = ConversionPool;
+    type MaxGlobalActiveEscrows = MaxGlobalActiveEscrows;
+    type PreservationPolicy = SufficiencyPreservation;
+    type DefaultHashAlgorithm = DefaultHashAlgorithm;
+    type RetentionPeriod = RetentionPeriod;
+    type RequireTakerAcceptance = RequireTakerAcceptance;
+    type CrossChainProof = MockRevealVerifier;
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type Scheduler = MockScheduler;
+}
+
+ord_parameter_types! {
+    pub const PauseGuardian: u64 = PAUSE_GUARDIAN;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -158,6 +409,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             (1, 1_000_000_000_000_000), // 1M DOT
             (2, 1_000_000_000_000_000), // 1M DOT
             (3, 1_000_000_000_000_000), // 1M DOT
+            (FEE_COLLECTOR, 1_000_000_000_000), // 1 DOT, so small fees don't fall below ED
         ],
     }
     .assimilate_storage(&mut storage)
@@ -176,6 +428,9 @@ pub const ALICE: u64 = 1;
 pub const BOB: u64 = 2;
 pub const CHARLIE: u64 = 3;
 pub const DAVE: u64 = 4;
+pub const FEE_COLLECTOR: u64 = 99;
+pub const CONVERSION_POOL: u64 = 98;
+pub const PAUSE_GUARDIAN: u64 = 97;
 
 // Helper functions for tests
 pub fn run_to_block(n: u64) {
@@ -1,10 +1,31 @@
 //! Simple tests for the fusion pallet
 
-use crate::{mock::*, Error, Event, AssetInfo, EscrowState};
+use crate::{
+    mock::*, Error, Event, AssetInfo, AssetKey, AssetType, CancelReason, EscrowEventKind, EscrowOptions, EscrowState,
+    EscrowStatus, HashAlgorithm, PalletSummary, PauseFlags, TemplateParams, XcmRoute, MAX_CANCEL_REASON_LEN,
+    COMPLETE_AUTH_CONTEXT, MAX_RECENT_EVENTS, MAX_SECRET_SIZE, MUTUAL_CANCEL_CONTEXT,
+};
+use codec::{Decode, Encode};
+use sp_runtime::{testing::TestSignature, traits::{AccountIdConversion, Dispatchable}};
 use frame_support::{
     assert_ok, assert_noop,
+    dispatch::GetDispatchInfo,
+    traits::{ConstU32, Get},
+    BoundedVec,
+};
+use xcm::{
+    latest::{Junction, Junctions, MultiLocation},
+    VersionedMultiLocation,
 };
 
+fn cancel_reason(reason: &[u8]) -> BoundedVec<u8, ConstU32<MAX_CANCEL_REASON_LEN>> {
+    reason.to_vec().try_into().unwrap()
+}
+
+fn bounded_secret(secret: &[u8]) -> BoundedVec<u8, ConstU32<MAX_SECRET_SIZE>> {
+    secret.to_vec().try_into().unwrap()
+}
+
 #[test]
 fn create_escrow_works() {
     new_test_ext().execute_with(|| {
@@ -33,12 +54,15 @@ fn create_escrow_works() {
         assert_eq!(escrow.amount, amount);
         assert_eq!(escrow.state, EscrowState::Created);
         
-        // Check that the event was emitted
+        // Check that the event was emitted with the escrow's funding account
         let event = last_event();
         assert!(matches!(
             event,
             RuntimeEvent::Fusion(Event::EscrowCreated { .. })
         ));
+        if let RuntimeEvent::Fusion(Event::EscrowCreated { escrow_id, escrow_account, .. }) = event {
+            assert_eq!(escrow_account, Fusion::escrow_account(&escrow_id));
+        }
     });
 }
 
@@ -97,7 +121,7 @@ fn complete_escrow_works() {
         assert_ok!(Fusion::complete_escrow(
             RuntimeOrigin::signed(2),
             0u64,
-            secret.to_vec(),
+            bounded_secret(secret),
         ));
         
         // Check that the escrow state was updated
@@ -130,7 +154,7 @@ fn cancel_escrow_works() {
         assert_ok!(Fusion::cancel_escrow(
             RuntimeOrigin::signed(1),
             0u64,
-            b"Timelock expired".to_vec(),
+            cancel_reason(b"Timelock expired"),
         ));
         
         // Check that the escrow state was updated
@@ -143,7 +167,7 @@ fn cancel_escrow_works() {
 fn emergency_pause_works() {
     new_test_ext().execute_with(|| {
         // Pause the pallet
-        assert_ok!(Fusion::emergency_pause(RuntimeOrigin::signed(1)));
+        assert_ok!(Fusion::emergency_pause(RuntimeOrigin::signed(PAUSE_GUARDIAN)));
         
         // Try to create escrow - should fail
         assert_noop!(
@@ -161,7 +185,7 @@ fn emergency_pause_works() {
         );
         
         // Unpause the pallet
-        assert_ok!(Fusion::emergency_unpause(RuntimeOrigin::signed(1)));
+        assert_ok!(Fusion::emergency_unpause(RuntimeOrigin::signed(PAUSE_GUARDIAN)));
         
         // Now creation should work
         assert_ok!(Fusion::create_escrow(
@@ -202,7 +226,7 @@ fn invalid_secret_fails() {
             Fusion::complete_escrow(
                 RuntimeOrigin::signed(2),
                 0u64,
-                b"wrong_secret".to_vec(),
+                bounded_secret(b"wrong_secret"),
             ),
             Error::<Test>::IncorrectSecret
         );
@@ -214,6 +238,7 @@ fn invalid_secret_fails() {
 #[test]
 fn create_stablecoin_escrow_works() {
     new_test_ext().execute_with(|| {
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1u32.into(), ALICE, true, 1));
         let beneficiary = 2u64;
         let stablecoin_asset = AssetInfo::Stablecoin { 
             asset_id: 1u32, 
@@ -309,6 +334,8 @@ fn asset_type_detection_works() {
 #[test]
 fn stablecoin_fund_and_complete_works() {
     new_test_ext().execute_with(|| {
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1u32.into(), ALICE, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1u32.into(), ALICE, 2_000_000_000));
         let beneficiary = 2u64;
         let stablecoin_asset = AssetInfo::Stablecoin { 
             asset_id: 1u32, 
@@ -341,7 +368,7 @@ fn stablecoin_fund_and_complete_works() {
         assert_ok!(Fusion::complete_escrow(
             RuntimeOrigin::signed(beneficiary),
             0u64,
-            secret,
+            bounded_secret(&secret),
         ));
         
         let escrow = Fusion::get_escrow(&0u64).unwrap();
@@ -384,7 +411,7 @@ fn nft_fund_and_complete_works() {
         assert_ok!(Fusion::complete_escrow(
             RuntimeOrigin::signed(beneficiary),
             0u64,
-            secret,
+            bounded_secret(&secret),
         ));
         
         let escrow = Fusion::get_escrow(&0u64).unwrap();
@@ -395,6 +422,7 @@ fn nft_fund_and_complete_works() {
 #[test]
 fn mixed_asset_escrows_work() {
     new_test_ext().execute_with(|| {
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1u32.into(), ALICE, true, 1));
         // Create multiple escrows with different asset types
         
         // Native DOT escrow
@@ -454,6 +482,7 @@ fn mixed_asset_escrows_work() {
 #[test]
 fn enhanced_asset_security_validations() {
     new_test_ext().execute_with(|| {
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1u32.into(), ALICE, true, 1));
         // Test edge cases for asset validation
         
         // Test stablecoin with maximum symbol length
@@ -499,3 +528,4489 @@ fn enhanced_asset_security_validations() {
         assert!(Fusion::get_escrow(&1u64).is_some());
     });
 }
+
+// ===== Third-party refund tests =====
+
+#[test]
+fn claim_expired_refund_pays_creator_not_caller() {
+    new_test_ext().execute_with(|| {
+        let alice_initial = Balances::free_balance(ALICE);
+        let charlie_initial = Balances::free_balance(CHARLIE);
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"refund_hash".to_vec(),
+            10u64,
+            b"refund test".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Balances::free_balance(ALICE), alice_initial - 1000);
+
+        // Not claimable before the timelock expires
+        assert_noop!(
+            Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64),
+            Error::<Test>::InvalidTimelock
+        );
+
+        run_to_block(20);
+
+        // A third party triggers the refund
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+
+        let escrow = Fusion::get_escrow(&0u64).unwrap();
+        assert_eq!(escrow.state, EscrowState::Cancelled);
+        assert_eq!(Balances::free_balance(ALICE), alice_initial);
+        assert_eq!(Balances::free_balance(CHARLIE), charlie_initial);
+
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::EscrowCancelled { canceller: CHARLIE, ref reason, .. })
+                if reason == &b"Refund claimed by third party".to_vec()
+        ));
+    });
+}
+
+// ===== Ethereum relay payload tests =====
+
+#[test]
+fn eth_claim_payload_matches_abi_layout() {
+    new_test_ext().execute_with(|| {
+        let secret = [0x11u8; 32];
+        let order_hash = [0x22u8; 32];
+
+        let payload = Fusion::eth_claim_payload(&secret, &order_hash).unwrap();
+        assert_eq!(payload.len(), 64);
+        assert_eq!(&payload[..32], &secret[..]);
+        assert_eq!(&payload[32..], &order_hash[..]);
+
+        // Short values are right-padded like `bytes32`
+        let payload = Fusion::eth_claim_payload(b"abc", &order_hash).unwrap();
+        let mut expected_secret = [0u8; 32];
+        expected_secret[..3].copy_from_slice(b"abc");
+        assert_eq!(&payload[..32], &expected_secret[..]);
+        assert_eq!(&payload[32..], &order_hash[..]);
+
+        // Values that don't fit in a word can't be encoded
+        assert_eq!(Fusion::eth_claim_payload(&[0u8; 33], &order_hash), None);
+    });
+}
+
+#[test]
+fn complete_escrow_emits_eth_claim_payload() {
+    new_test_ext().execute_with(|| {
+        let secret = [0x42u8; 32];
+        let hashlock = sp_core::hashing::sha2_256(&secret).to_vec();
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock.clone(),
+            100u64,
+            b"relay payload".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(&secret)));
+
+        let mut expected = secret.to_vec();
+        expected.extend_from_slice(&hashlock);
+        System::assert_last_event(RuntimeEvent::Fusion(Event::EthClaimPayload {
+            escrow_id: 0u64,
+            payload: expected,
+        }));
+    });
+}
+
+// ===== State index tests =====
+
+#[test]
+fn state_index_tracks_lifecycle() {
+    new_test_ext().execute_with(|| {
+        let secret = b"index_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"index test".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::escrows_in_state(EscrowState::Created), vec![0u64]);
+        assert!(Fusion::escrows_in_state(EscrowState::Active).is_empty());
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert!(Fusion::escrows_in_state(EscrowState::Created).is_empty());
+        assert_eq!(Fusion::escrows_in_state(EscrowState::Active), vec![0u64]);
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert!(Fusion::escrows_in_state(EscrowState::Active).is_empty());
+        assert_eq!(Fusion::escrows_in_state(EscrowState::Completed), vec![0u64]);
+    });
+}
+
+// ===== Cancel before funding tests =====
+
+#[test]
+fn cancel_before_funding_abandons_unfunded_escrow() {
+    new_test_ext().execute_with(|| {
+        let alice_initial = Balances::free_balance(ALICE);
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"abandon_hash".to_vec(),
+            100u64,
+            b"abandon test".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::get_account_escrows(&ALICE), vec![0u64]);
+
+        // Only the creator may abandon it
+        assert_noop!(
+            Fusion::cancel_before_funding(RuntimeOrigin::signed(BOB), 0u64),
+            Error::<Test>::NotCreator
+        );
+
+        // No timelock wait is needed
+        assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 0u64));
+
+        let escrow = Fusion::get_escrow(&0u64).unwrap();
+        assert_eq!(escrow.state, EscrowState::Cancelled);
+        assert!(Fusion::get_account_escrows(&ALICE).is_empty());
+        assert_eq!(Balances::free_balance(ALICE), alice_initial);
+    });
+}
+
+#[test]
+fn cancel_before_funding_rejects_funded_escrow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"funded_hash".to_vec(),
+            100u64,
+            b"funded test".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 0u64),
+            Error::<Test>::InvalidEscrowState
+        );
+    });
+}
+
+// ===== Minimum amount tests =====
+
+#[test]
+fn min_escrow_amount_boundary() {
+    new_test_ext().execute_with(|| {
+        let min = <Test as crate::Config>::MinEscrowAmount::get();
+
+        assert_noop!(
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                min - 1,
+                b"dust_hash".to_vec(),
+                100u64,
+                b"dust".to_vec(),
+                None,
+            ),
+            Error::<Test>::AmountBelowMinimum
+        );
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            min,
+            b"min_hash".to_vec(),
+            100u64,
+            b"minimum".to_vec(),
+            None,
+        ));
+    });
+}
+
+#[test]
+fn nft_escrows_are_exempt_from_min_amount() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Nft {
+                collection_id: 1u32,
+                item_id: 7u32,
+                metadata: b"NFT #7".to_vec().try_into().unwrap()
+            },
+            1u128,
+            b"nft_min_hash".to_vec(),
+            100u64,
+            b"NFT exempt".to_vec(),
+            None,
+        ));
+    });
+}
+
+// ===== XCM routing tests =====
+
+fn xcm_route_to(destination: MultiLocation) -> XcmRoute {
+    XcmRoute {
+        destination: VersionedMultiLocation::from(destination).encode().try_into().unwrap(),
+        route_data: Default::default(),
+    }
+}
+
+fn parachain_route(para_id: u32) -> XcmRoute {
+    xcm_route_to(MultiLocation::new(1, Junctions::X1(Junction::Parachain(para_id))))
+}
+
+#[test]
+fn complete_escrow_routes_via_xcm() {
+    new_test_ext().execute_with(|| {
+        let secret = b"xcm_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"route to Acala".to_vec(),
+            Some(parachain_route(2000)),
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        let bob_initial = Balances::free_balance(BOB);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+
+        // Funds left via XCM rather than being credited locally
+        assert_eq!(XcmExecutedCount::get(), 1);
+        assert_eq!(Balances::free_balance(BOB), bob_initial);
+        assert!(System::events().iter().any(|e| matches!(
+            e.event,
+            RuntimeEvent::Fusion(Event::XcmTransferInitiated { escrow_id: 0, beneficiary: BOB, amount: 1000, .. })
+        )));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+    });
+}
+
+#[test]
+fn failed_xcm_execution_reverts_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = b"xcm_failure";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"route to Acala".to_vec(),
+            Some(parachain_route(2000)),
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        XcmExecutionFails::set(true);
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)),
+            Error::<Test>::XcmExecutionFailed
+        );
+    });
+}
+
+#[test]
+fn xcm_transfer_event_describes_cross_chain_leg() {
+    new_test_ext().execute_with(|| {
+        let secret = b"xcm_event_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+        let destination = MultiLocation::new(
+            1,
+            Junctions::X2(
+                Junction::Parachain(2000),
+                Junction::AccountId32 { network: None, id: [7u8; 32] },
+            ),
+        );
+        let route = xcm_route_to(destination);
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"xcm event".to_vec(),
+            Some(route),
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+
+        let expected = RuntimeEvent::Fusion(Event::XcmTransferInitiated {
+            escrow_id: 0u64,
+            destination,
+            asset: (MultiLocation::here(), 1000u128).into(),
+            beneficiary: BOB,
+            amount: 1000u128,
+            message_hash: LastXcmHash::get(),
+        });
+        assert!(System::events().iter().any(|record| record.event == expected));
+        assert_ne!(LastXcmHash::get(), [0u8; 32]);
+    });
+}
+
+#[test]
+fn xcm_route_validated_at_creation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::validate_xcm_route(&parachain_route(2000)));
+
+        let garbage =
+            XcmRoute { destination: b"not a location".to_vec().try_into().unwrap(), route_data: Default::default() };
+        let relay = xcm_route_to(MultiLocation::parent());
+        for route in [garbage, relay] {
+            assert_noop!(
+                Fusion::create_escrow(
+                    RuntimeOrigin::signed(ALICE),
+                    BOB,
+                    AssetInfo::Native,
+                    1000u128,
+                    b"bad_route_hash".to_vec(),
+                    100u64,
+                    b"bad route".to_vec(),
+                    Some(route),
+                ),
+                Error::<Test>::InvalidXcmRoute
+            );
+        }
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"good_route_hash".to_vec(),
+            100u64,
+            b"good route".to_vec(),
+            Some(parachain_route(2000)),
+        ));
+    });
+}
+
+// ===== Top-up tests =====
+
+#[test]
+fn top_up_escrow_updates_amount_while_created() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"top_up_hash".to_vec(),
+            100u64,
+            b"top up".to_vec(),
+            None,
+        ));
+
+        assert_noop!(
+            Fusion::top_up_escrow(RuntimeOrigin::signed(BOB), 0u64, 1500u128),
+            Error::<Test>::NotCreator
+        );
+
+        assert_ok!(Fusion::top_up_escrow(RuntimeOrigin::signed(ALICE), 0u64, 1500u128));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().amount, 1500u128);
+        System::assert_last_event(RuntimeEvent::Fusion(Event::EscrowAmountUpdated {
+            escrow_id: 0u64,
+            old: 1000u128,
+            new: 1500u128,
+        }));
+
+        // Funding moves the updated amount
+        let alice_before = Balances::free_balance(ALICE);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Balances::free_balance(ALICE), alice_before - 1500);
+    });
+}
+
+#[test]
+fn top_up_escrow_rejected_once_active() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"active_top_up".to_vec(),
+            100u64,
+            b"top up".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::top_up_escrow(RuntimeOrigin::signed(ALICE), 0u64, 1500u128),
+            Error::<Test>::InvalidEscrowState
+        );
+    });
+}
+
+// ===== Deterministic ID tests =====
+
+#[test]
+fn create_escrow_with_id_derives_same_id_from_same_inputs() {
+    new_test_ext().execute_with(|| {
+        let hashlock = b"shared_order_hash".to_vec();
+        let expected = Fusion::derive_escrow_id(&hashlock, &ALICE);
+        assert_eq!(expected, Fusion::derive_escrow_id(&hashlock, &ALICE));
+        assert_ne!(expected, Fusion::derive_escrow_id(&hashlock, &BOB));
+
+        assert_ok!(Fusion::create_escrow_with_id(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"derived".to_vec(),
+            None,
+        ));
+
+        assert!(Fusion::get_escrow(&expected).is_some());
+        // Counter-based path is untouched
+        assert_eq!(Fusion::next_escrow_id(), 0u64);
+    });
+}
+
+#[test]
+fn derived_ids_never_block_the_counter() {
+    new_test_ext().execute_with(|| {
+        // Derived IDs live in their own half of the ID space
+        let derived = Fusion::derive_escrow_id(b"ground_hashlock", &ALICE);
+        assert_eq!(derived & crate::DERIVED_ESCROW_ID_FLAG, crate::DERIVED_ESCROW_ID_FLAG);
+
+        assert_ok!(Fusion::create_escrow_with_id(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"ground_hashlock".to_vec(),
+            100u64,
+            b"derived".to_vec(),
+            None,
+        ));
+        create_native_escrow(b"counter_hashlock", 1000);
+        assert!(Fusion::get_escrow(&0u64).is_some());
+        assert_eq!(Fusion::next_escrow_id(), 1u64);
+    });
+}
+
+#[test]
+fn create_escrow_with_id_rejects_collision() {
+    new_test_ext().execute_with(|| {
+        let hashlock = b"colliding_hash".to_vec();
+        assert_ok!(Fusion::create_escrow_with_id(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock.clone(),
+            100u64,
+            b"first".to_vec(),
+            None,
+        ));
+        // Abandoning it frees the hashlock but not the derived ID
+        let escrow_id = Fusion::derive_escrow_id(&hashlock, &ALICE);
+        assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), escrow_id));
+
+        assert_noop!(
+            Fusion::create_escrow_with_id(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock,
+                100u64,
+                b"second".to_vec(),
+                None,
+            ),
+            Error::<Test>::EscrowIdCollision
+        );
+    });
+}
+
+// ===== TVL tests =====
+
+#[test]
+fn total_value_locked_tracks_funding_and_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = b"tvl_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"tvl one".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            2000u128,
+            b"tvl_other_hash".to_vec(),
+            100u64,
+            b"tvl two".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::total_locked(AssetType::Native), 0);
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+        assert_eq!(Fusion::total_locked(AssetType::Native), 3000);
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::total_locked(AssetType::Native), 2000);
+
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 1u64, cancel_reason(b"done")));
+        assert_eq!(Fusion::total_locked(AssetType::Native), 0);
+    });
+}
+
+// ===== Beneficiary reassignment tests =====
+
+#[test]
+fn reassign_beneficiary_moves_index_entry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"reassign_hash".to_vec(),
+            100u64,
+            b"reassign".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::beneficiary_escrows(BOB).to_vec(), vec![0u64]);
+
+        assert_noop!(
+            Fusion::reassign_beneficiary(RuntimeOrigin::signed(ALICE), 0u64, ALICE),
+            Error::<Test>::InvalidBeneficiary
+        );
+        assert_noop!(
+            Fusion::reassign_beneficiary(RuntimeOrigin::signed(BOB), 0u64, CHARLIE),
+            Error::<Test>::NotCreator
+        );
+
+        assert_ok!(Fusion::reassign_beneficiary(RuntimeOrigin::signed(ALICE), 0u64, CHARLIE));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().beneficiary, CHARLIE);
+        assert!(Fusion::beneficiary_escrows(BOB).is_empty());
+        assert_eq!(Fusion::beneficiary_escrows(CHARLIE).to_vec(), vec![0u64]);
+        assert_eq!(Fusion::account_escrows(ALICE).to_vec(), vec![0u64]);
+
+        // Locked in once funded
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_noop!(
+            Fusion::reassign_beneficiary(RuntimeOrigin::signed(ALICE), 0u64, BOB),
+            Error::<Test>::InvalidEscrowState
+        );
+    });
+}
+
+// ===== Pause flag tests =====
+
+#[test]
+fn emergency_preset_still_allows_complete_and_cancel() {
+    new_test_ext().execute_with(|| {
+        let secret = b"paused_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"complete me".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"paused_cancel_hash".to_vec(),
+            100u64,
+            b"cancel me".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+
+        assert_ok!(Fusion::emergency_pause(RuntimeOrigin::root()));
+        assert_eq!(Fusion::pause_flags(), PauseFlags::emergency());
+
+        assert_noop!(
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                b"blocked_hash".to_vec(),
+                100u64,
+                b"blocked".to_vec(),
+                None,
+            ),
+            Error::<Test>::EmergencyPaused
+        );
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 1u64, cancel_reason(b"incident")));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+#[test]
+fn set_pause_flags_controls_each_operation() {
+    new_test_ext().execute_with(|| {
+        let flags = PauseFlags { create: false, fund: true, complete: false, cancel: true };
+        assert_noop!(
+            Fusion::set_pause_flags(RuntimeOrigin::signed(ALICE), flags),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Fusion::set_pause_flags(RuntimeOrigin::root(), flags));
+        System::assert_last_event(RuntimeEvent::Fusion(Event::PauseFlagsUpdated { flags }));
+
+        // Creation is open, funding and cancelling are not
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"flags_hash".to_vec(),
+            100u64,
+            b"flags".to_vec(),
+            None,
+        ));
+        assert_noop!(
+            Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64),
+            Error::<Test>::EmergencyPaused
+        );
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"no")),
+            Error::<Test>::EmergencyPaused
+        );
+
+        assert_ok!(Fusion::set_pause_flags(RuntimeOrigin::root(), PauseFlags::default()));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+    });
+}
+
+// ===== Third-party funding tests =====
+
+#[test]
+fn third_party_can_fund_and_refund_goes_to_creator() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"agent_funded_hash".to_vec(),
+            100u64,
+            b"agent".to_vec(),
+            None,
+        ));
+
+        let alice_before = Balances::free_balance(ALICE);
+        let charlie_before = Balances::free_balance(CHARLIE);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(CHARLIE), 0u64));
+        System::assert_last_event(RuntimeEvent::Fusion(Event::EscrowFunded {
+            escrow_id: 0u64,
+            funder: CHARLIE,
+            at_block: 1,
+            at_time: 12,
+        }));
+
+        let escrow = Fusion::get_escrow(&0u64).unwrap();
+        assert_eq!(escrow.funder, Some(CHARLIE));
+        assert_eq!(escrow.state, EscrowState::Active);
+        assert_eq!(Balances::free_balance(CHARLIE), charlie_before - 1000);
+        assert_eq!(Balances::free_balance(ALICE), alice_before);
+
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"refund")));
+        assert_eq!(Balances::free_balance(ALICE), alice_before + 1000);
+    });
+}
+
+// ===== Status tests =====
+
+#[test]
+fn escrow_status_follows_lifecycle() {
+    new_test_ext().execute_with(|| {
+        let secret = b"status_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::NotFound);
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"status".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::AwaitingFunding);
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::ActiveCompletable { remaining: 100 });
+
+        run_to_block(51);
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::ActiveCompletable { remaining: 50 });
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::Completed);
+    });
+}
+
+#[test]
+fn escrow_status_reports_expiry_and_cancellation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"status_expiry_hash".to_vec(),
+            100u64,
+            b"status".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        run_to_block(101);
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::ActiveExpired);
+
+        run_to_block(101 + CancelSafetyGap::get());
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::Cancelled);
+    });
+}
+
+// ===== Account index pruning tests =====
+
+#[test]
+fn completed_escrows_free_account_slots() {
+    new_test_ext().execute_with(|| {
+        let max: u32 = <Test as crate::Config>::MaxEscrowsPerAccount::get();
+        for i in 0..max {
+            let secret = (b"slot", i).encode();
+            let hashlock = sp_core::hashing::sha2_256(&secret).to_vec();
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock,
+                100u64,
+                b"slot".to_vec(),
+                None,
+            ));
+            let escrow_id = i as u64;
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id));
+            assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), escrow_id, bounded_secret(&secret)));
+        }
+
+        assert!(Fusion::get_account_escrows(&ALICE).is_empty());
+        assert!(Fusion::beneficiary_escrows(BOB).is_empty());
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"one_more_hash".to_vec(),
+            100u64,
+            b"one more".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::get_account_escrows(&ALICE), vec![max as u64]);
+    });
+}
+
+// ===== Signed authorization tests =====
+
+/// `signer`'s authorization to complete `escrow_id` with `secret`, bound to escrow 0's hashlock
+fn completion_auth(signer: u64, escrow_id: u64, secret: &[u8]) -> TestSignature {
+    let hashlock = Fusion::get_escrow(&0u64).unwrap().hashlock;
+    let secret_hash = sp_core::hashing::sha2_256(secret);
+    TestSignature(signer, (COMPLETE_AUTH_CONTEXT, System::block_hash(0), escrow_id, hashlock, secret_hash).encode())
+}
+
+#[test]
+fn complete_escrow_with_auth_accepts_creator_signature() {
+    new_test_ext().execute_with(|| {
+        let secret = bounded_secret(b"gasless_secret");
+        let hashlock = sp_core::hashing::sha2_256(&secret).to_vec();
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"gasless".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        let bob_before = Balances::free_balance(BOB);
+        let sig = completion_auth(ALICE, 0, &secret);
+        assert_ok!(Fusion::complete_escrow_with_auth(RuntimeOrigin::signed(CHARLIE), 0u64, secret, sig));
+
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Balances::free_balance(BOB), bob_before + 1000);
+    });
+}
+
+#[test]
+fn complete_escrow_with_auth_rejects_tampered_signature() {
+    new_test_ext().execute_with(|| {
+        let secret = bounded_secret(b"tampered_secret");
+        let hashlock = sp_core::hashing::sha2_256(&secret).to_vec();
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock,
+            100u64,
+            b"tampered".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // Signed over a different escrow ID
+        let wrong_message = completion_auth(ALICE, 1, &secret);
+        assert_noop!(
+            Fusion::complete_escrow_with_auth(RuntimeOrigin::signed(CHARLIE), 0u64, secret.clone(), wrong_message),
+            Error::<Test>::InvalidAuthorization
+        );
+
+        // Signed without the domain tag, chain and hashlock
+        let untagged = TestSignature(ALICE, (0u64, sp_core::hashing::sha2_256(&secret)).encode());
+        assert_noop!(
+            Fusion::complete_escrow_with_auth(RuntimeOrigin::signed(CHARLIE), 0u64, secret.clone(), untagged),
+            Error::<Test>::InvalidAuthorization
+        );
+
+        // Signed by someone other than the creator
+        let wrong_signer = completion_auth(CHARLIE, 0, &secret);
+        assert_noop!(
+            Fusion::complete_escrow_with_auth(RuntimeOrigin::signed(CHARLIE), 0u64, secret, wrong_signer),
+            Error::<Test>::InvalidAuthorization
+        );
+    });
+}
+
+// ===== Migration tests =====
+
+#[test]
+fn legacy_escrows_migrate_into_current_layout() {
+    use crate::migrations::{v0, MigrateLegacyEscrows, LEGACY_METADATA};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Fusion>();
+        // A current-format escrow under the same `Escrows` prefix
+        create_native_escrow(b"current_escrow", 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let current = Fusion::get_escrow(&0u64).unwrap();
+        v0::Escrows::<Test>::insert([1u8; 32], (ALICE, 50u64, 1_000u32));
+        v0::Escrows::<Test>::insert([2u8; 32], (ALICE, 60u64, 2_000u32));
+        v0::Escrows::<Test>::insert([3u8; 32], (BOB, 70u64, 3_000u32));
+
+        MigrateLegacyEscrows::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Fusion::on_chain_storage_version(), StorageVersion::new(1));
+        assert_eq!(v0::Escrows::<Test>::iter_keys().count(), 0);
+        assert_eq!(Fusion::get_escrow(&0u64), Some(current));
+        assert_eq!(Fusion::next_escrow_id(), 4u64);
+        assert_eq!(Fusion::escrows_in_state(EscrowState::Created).len(), 3);
+        assert_eq!(Fusion::get_account_escrows(&ALICE).len(), 3);
+        assert_eq!(Fusion::get_account_escrows(&BOB).len(), 1);
+
+        let bob_id = Fusion::get_account_escrows(&BOB)[0];
+        let escrow = Fusion::get_escrow(&bob_id).unwrap();
+        assert_eq!(escrow.creator, BOB);
+        assert_eq!(escrow.beneficiary, BOB);
+        assert_eq!(escrow.asset, AssetInfo::Native);
+        assert_eq!(escrow.amount, 3_000u128);
+        assert_eq!(escrow.hashlock.to_vec(), vec![3u8; 32]);
+        assert_eq!(escrow.timelock, 70u64);
+        assert_eq!(escrow.metadata.to_vec(), LEGACY_METADATA.to_vec());
+
+        // Running again is a no-op
+        MigrateLegacyEscrows::<Test>::on_runtime_upgrade();
+        assert_eq!(Fusion::next_escrow_id(), 4u64);
+    });
+}
+
+#[test]
+fn storage_version_stub_sets_version_one() {
+    use crate::migrations::InitializeStorageVersion;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // A live chain that predates storage versioning
+        StorageVersion::new(0).put::<Fusion>();
+        create_native_escrow(b"versioned_escrow", 1000);
+
+        let weight = InitializeStorageVersion::<Test>::on_runtime_upgrade();
+        assert_eq!(weight, <Test as frame_system::Config>::DbWeight::get().writes(1));
+        assert_eq!(Fusion::on_chain_storage_version(), StorageVersion::new(1));
+        #[cfg(feature = "try-runtime")]
+        assert_ok!(InitializeStorageVersion::<Test>::post_upgrade(Vec::new()));
+
+        // Existing escrows are left alone
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Created);
+    });
+}
+
+#[test]
+fn genesis_starts_at_current_storage_version() {
+    use frame_support::traits::GetStorageVersion;
+    use sp_runtime::BuildStorage;
+
+    let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // Fresh chains must not pick up the legacy migration
+        assert_eq!(Fusion::on_chain_storage_version(), Fusion::current_storage_version());
+    });
+}
+
+// ===== Beneficiary bond tests =====
+
+fn create_bonded_escrow(hashlock: Vec<u8>, bond: u128) {
+    assert_ok!(Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        hashlock,
+        100u64,
+        b"bonded".to_vec(),
+        None,
+        EscrowOptions { bond: Some(bond), ..Default::default() },
+    ));
+}
+
+#[test]
+fn bond_forfeited_to_creator_on_expiry() {
+    new_test_ext().execute_with(|| {
+        create_bonded_escrow(b"forfeit_hash".to_vec(), 5000);
+
+        assert_noop!(
+            Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64),
+            Error::<Test>::BondNotPosted
+        );
+        assert_noop!(
+            Fusion::post_bond(RuntimeOrigin::signed(CHARLIE), 0u64),
+            Error::<Test>::NotBeneficiary
+        );
+
+        let bob_before = Balances::free_balance(BOB);
+        assert_ok!(Fusion::post_bond(RuntimeOrigin::signed(BOB), 0u64));
+        assert_eq!(Balances::reserved_balance(BOB), 5000);
+        assert_noop!(
+            Fusion::post_bond(RuntimeOrigin::signed(BOB), 0u64),
+            Error::<Test>::BondAlreadyPosted
+        );
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let alice_funded = Balances::free_balance(ALICE);
+
+        run_to_block(101 + CancelSafetyGap::get());
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"expired")));
+
+        assert_eq!(Balances::free_balance(ALICE), alice_funded + 1000 + 5000);
+        assert_eq!(Balances::reserved_balance(BOB), 0);
+        assert_eq!(Balances::free_balance(BOB), bob_before - 5000);
+        System::assert_has_event(RuntimeEvent::Fusion(Event::BondForfeited {
+            escrow_id: 0u64,
+            creator: ALICE,
+            amount: 5000,
+        }));
+    });
+}
+
+#[test]
+fn bond_returned_to_beneficiary_on_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = b"bond_secret";
+        create_bonded_escrow(sp_core::hashing::sha2_256(secret).to_vec(), 5000);
+
+        let bob_before = Balances::free_balance(BOB);
+        assert_ok!(Fusion::post_bond(RuntimeOrigin::signed(BOB), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+
+        assert_eq!(Balances::reserved_balance(BOB), 0);
+        assert_eq!(Balances::free_balance(BOB), bob_before + 1000);
+        assert!(!Fusion::get_escrow(&0u64).unwrap().bond_posted);
+    });
+}
+
+// ===== Recent events tests =====
+
+#[test]
+fn recent_events_keep_latest_entries_in_order() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"recent_0".to_vec(),
+            100u64,
+            b"recent".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(
+            Fusion::recent_events().iter().map(|e| e.kind).collect::<Vec<_>>(),
+            vec![EscrowEventKind::Created, EscrowEventKind::Funded]
+        );
+
+        // 2 events per escrow: 40 escrows overflow the buffer
+        for i in 1..=40u64 {
+            run_to_block(i + 1);
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(CHARLIE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                (b"recent", i).encode(),
+                100u64,
+                b"recent".to_vec(),
+                None,
+            ));
+            assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(CHARLIE), i));
+        }
+
+        let events = Fusion::recent_events();
+        assert_eq!(events.len() as u32, MAX_RECENT_EVENTS);
+        // Oldest surviving entry is escrow 9's creation; the last is escrow 40's cancellation
+        assert_eq!(events[0].escrow_id, 9u64);
+        assert_eq!(events[0].kind, EscrowEventKind::Created);
+        let last = events.last().unwrap();
+        assert_eq!((last.escrow_id, last.kind, last.block), (40u64, EscrowEventKind::Cancelled, 41u64));
+        assert!(events.windows(2).all(|pair| pair[0].block <= pair[1].block));
+    });
+}
+
+// ===== Metadata update tests =====
+
+#[test]
+fn update_metadata_while_active() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"metadata_hash".to_vec(),
+            100u64,
+            b"original".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::update_metadata(RuntimeOrigin::signed(BOB), 0u64, b"hijack".to_vec()),
+            Error::<Test>::NotCreator
+        );
+        assert_noop!(
+            Fusion::update_metadata(RuntimeOrigin::signed(ALICE), 0u64, vec![0u8; 1025]),
+            Error::<Test>::InvalidMetadata
+        );
+
+        assert_ok!(Fusion::update_metadata(RuntimeOrigin::signed(ALICE), 0u64, b"eth tx 0xabc".to_vec()));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().metadata.to_vec(), b"eth tx 0xabc".to_vec());
+        System::assert_last_event(RuntimeEvent::Fusion(Event::MetadataUpdated { escrow_id: 0u64 }));
+    });
+}
+
+#[test]
+fn update_metadata_rejected_after_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = b"metadata_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"original".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+
+        assert_noop!(
+            Fusion::update_metadata(RuntimeOrigin::signed(ALICE), 0u64, b"late".to_vec()),
+            Error::<Test>::InvalidEscrowState
+        );
+    });
+}
+
+// ===== Amount normalization tests =====
+
+#[test]
+fn stablecoin_amounts_normalize_across_decimals() {
+    new_test_ext().execute_with(|| {
+        let usdc_6 = AssetInfo::Stablecoin {
+            asset_id: 1u32,
+            decimals: 6u8,
+            symbol: b"USDC".to_vec().try_into().unwrap(),
+        };
+        let usdc_18 = AssetInfo::Stablecoin {
+            asset_id: 2u32,
+            decimals: 18u8,
+            symbol: b"USDC".to_vec().try_into().unwrap(),
+        };
+
+        // 1000 USDC on each scale
+        let six = Fusion::normalized_amount(&usdc_6, 1_000_000_000u128);
+        let eighteen = Fusion::normalized_amount(&usdc_18, 1_000_000_000_000_000_000_000u128);
+        assert_eq!(six, eighteen);
+        assert_eq!(six, 1_000 * 10u128.pow(18));
+
+        // Native DOT has 10 decimals
+        assert_eq!(Fusion::normalized_amount(&AssetInfo::Native, 10_000_000_000u128), 10u128.pow(18));
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            usdc_6,
+            1_000_000_000u128,
+            b"normalized_hash".to_vec(),
+            100u64,
+            b"usdc".to_vec(),
+            None,
+        ));
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Fusion(Event::EscrowCreated { amount: 1_000_000_000, normalized_amount, .. })
+                if normalized_amount == eighteen
+        )));
+    });
+}
+
+// ===== Fee estimation tests =====
+
+#[test]
+fn estimate_create_fee_matches_creation_cost() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Fusion::estimate_create_fee(&AssetInfo::Native, 1000u128), 0);
+        assert_eq!(Fusion::estimate_create_fee(&AssetInfo::Asset(1u32), 5_000_000u128), 0);
+
+        // Quoting leaves no trace in state
+        assert_eq!(Fusion::next_escrow_id(), 0u64);
+        assert!(System::events().is_empty());
+
+        // Creating takes exactly the quote from the creator
+        let alice_before = Balances::free_balance(ALICE);
+        create_native_escrow(b"quoted_hash", 1000);
+        assert_eq!(Balances::free_balance(ALICE), alice_before - Fusion::estimate_create_fee(&AssetInfo::Native, 1000));
+    });
+}
+
+// ===== Idempotent creation tests =====
+
+#[test]
+fn identical_resubmission_is_a_noop() {
+    new_test_ext().execute_with(|| {
+        let create = || {
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                b"resubmitted_hash".to_vec(),
+                100u64,
+                b"resubmit".to_vec(),
+                None,
+            )
+        };
+        assert_ok!(create());
+        let events = System::events().len();
+
+        run_to_block(5);
+        assert_ok!(create());
+        assert_eq!(Fusion::next_escrow_id(), 1u64);
+        assert_eq!(Fusion::get_account_escrows(&ALICE), vec![0u64]);
+        assert_eq!(System::events().len(), events);
+    });
+}
+
+#[test]
+fn conflicting_resubmission_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"conflicting_hash".to_vec(),
+            100u64,
+            b"original".to_vec(),
+            None,
+        ));
+
+        assert_noop!(
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                2000u128,
+                b"conflicting_hash".to_vec(),
+                100u64,
+                b"original".to_vec(),
+                None,
+            ),
+            Error::<Test>::DuplicateHashlock
+        );
+
+        // Another creator may reuse the hashlock, e.g. for a linked leg
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(CHARLIE),
+            BOB,
+            AssetInfo::Native,
+            2000u128,
+            b"conflicting_hash".to_vec(),
+            100u64,
+            b"linked".to_vec(),
+            None,
+        ));
+    });
+}
+
+// ===== Escrow freeze tests =====
+
+#[test]
+fn frozen_escrow_blocks_completion_until_unfrozen() {
+    new_test_ext().execute_with(|| {
+        let secret = b"frozen_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"disputed".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::freeze_escrow(RuntimeOrigin::signed(ALICE), 0u64),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Fusion::freeze_escrow(RuntimeOrigin::root(), 0u64));
+        System::assert_last_event(RuntimeEvent::Fusion(Event::EscrowFrozen { escrow_id: 0u64 }));
+
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)),
+            Error::<Test>::EscrowFrozen
+        );
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"cancel")),
+            Error::<Test>::EscrowFrozen
+        );
+
+        assert_ok!(Fusion::unfreeze_escrow(RuntimeOrigin::root(), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+    });
+}
+
+// ===== Linked reveal tests =====
+
+#[test]
+fn reveal_secret_completes_linked_escrows() {
+    new_test_ext().execute_with(|| {
+        let secret = b"multi_leg_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            hashlock.clone(),
+            100u64,
+            b"leg one".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(CHARLIE),
+            DAVE,
+            AssetInfo::Native,
+            2000u128,
+            hashlock.clone(),
+            100u64,
+            b"leg two".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(CHARLIE), 1u64));
+
+        let bob_before = Balances::free_balance(BOB);
+        assert_ok!(Fusion::reveal_secret(RuntimeOrigin::signed(ALICE), bounded_secret(secret)));
+
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Balances::free_balance(BOB), bob_before + 1000);
+        assert_eq!(Balances::free_balance(DAVE), 2000);
+        System::assert_last_event(RuntimeEvent::Fusion(Event::SecretRevealed {
+            hashlock,
+            completed: vec![0, 1],
+            failed: vec![],
+        }));
+
+        // Nothing left to unlock
+        assert_noop!(
+            Fusion::reveal_secret(RuntimeOrigin::signed(ALICE), bounded_secret(secret)),
+            Error::<Test>::EscrowNotFound
+        );
+    });
+}
+
+#[test]
+fn reveal_secret_settles_around_a_failing_escrow() {
+    new_test_ext().execute_with(|| {
+        OracleRate::set(None);
+        let secret = b"poisoned_secret";
+        let hashlock = sp_core::hashing::sha2_256(secret).to_vec();
+
+        create_native_escrow(&hashlock, 1000);
+        // Can never complete: its cross-asset payout has no price
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(CHARLIE),
+            DAVE,
+            AssetInfo::Native,
+            1000u128,
+            hashlock.clone(),
+            100u64,
+            b"poison".to_vec(),
+            None,
+            EscrowOptions { payout_asset: Some(AssetKey::Asset(7)), ..Default::default() },
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(CHARLIE), 1u64));
+
+        assert_ok!(Fusion::reveal_secret(RuntimeOrigin::signed(ALICE), bounded_secret(secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().state, EscrowState::Active);
+        System::assert_last_event(RuntimeEvent::Fusion(Event::SecretRevealed {
+            hashlock,
+            completed: vec![0],
+            failed: vec![1],
+        }));
+
+        // With nothing else to settle, the failure surfaces
+        assert_noop!(
+            Fusion::reveal_secret(RuntimeOrigin::signed(ALICE), bounded_secret(secret)),
+            Error::<Test>::PriceUnavailable
+        );
+    });
+}
+
+// ===== Preservation policy tests =====
+
+#[test]
+fn preservation_follows_asset_sufficiency() {
+    use frame_support::traits::tokens::Preservation;
+
+    new_test_ext().execute_with(|| {
+        NonSufficientAssets::set(vec![7u32]);
+
+        assert_eq!(Fusion::preservation_for(&AssetInfo::Asset(7u32)), Preservation::Preserve);
+        assert_eq!(Fusion::preservation_for(&AssetInfo::Asset(1u32)), Preservation::Expendable);
+        assert_eq!(Fusion::preservation_for(&AssetInfo::Native), Preservation::Expendable);
+    });
+}
+
+#[test]
+fn native_funding_honours_preserve_policy() {
+    new_test_ext().execute_with(|| {
+        // Account 4 holds just enough to fund, leaving nothing behind
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), DAVE, 1000));
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(DAVE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"drain_hash".to_vec(),
+            100u64,
+            b"drain".to_vec(),
+            None,
+        ));
+
+        // Native is expendable, so the funder may be reaped
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(DAVE), 0u64));
+        assert_eq!(Balances::free_balance(DAVE), 0);
+    });
+}
+
+// ===== Cancel reason tests =====
+
+fn last_cancel_reason() -> CancelReason {
+    match last_event() {
+        RuntimeEvent::Fusion(Event::EscrowCancelled { cause, .. }) => cause,
+        other => panic!("expected EscrowCancelled, got {:?}", other),
+    }
+}
+
+#[test]
+fn each_cancel_path_emits_its_reason() {
+    new_test_ext().execute_with(|| {
+        for hashlock in [b"reason_0", b"reason_1", b"reason_2", b"reason_3", b"reason_4"] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock.to_vec(),
+                10u64,
+                b"cancel reasons".to_vec(),
+                None,
+            ));
+        }
+        for escrow_id in 2u64..5 {
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id));
+        }
+
+        assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(last_cancel_reason(), CancelReason::CancelledBeforeFunding);
+
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 1u64, cancel_reason(b"unfunded")));
+        assert_eq!(last_cancel_reason(), CancelReason::CancelledBeforeFunding);
+
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 2u64, cancel_reason(b"changed mind")));
+        assert_eq!(last_cancel_reason(), CancelReason::CancelledByCreator);
+
+        run_to_block(20);
+
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 3u64, cancel_reason(b"expired")));
+        assert_eq!(last_cancel_reason(), CancelReason::TimelockExpired);
+
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 4u64));
+        assert_eq!(last_cancel_reason(), CancelReason::RefundClaimedByThirdParty);
+    });
+}
+
+// ===== Expiry sweep tests =====
+
+#[test]
+fn sweep_expired_refunds_only_eligible_escrows() {
+    new_test_ext().execute_with(|| {
+        let secret = b"sweep_secret";
+        let alice_initial = Balances::free_balance(ALICE);
+
+        // 0 and 1 expire at block 11, 2 stays live, 3 is completed before expiry
+        for (hashlock, duration) in [
+            (b"sweep_0".to_vec(), 10u64),
+            (b"sweep_1".to_vec(), 10u64),
+            (b"sweep_2".to_vec(), 100u64),
+            (sp_core::hashing::sha2_256(secret).to_vec(), 10u64),
+        ] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock,
+                duration,
+                b"sweep".to_vec(),
+                None,
+            ));
+        }
+        for escrow_id in 0u64..4 {
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id));
+        }
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 3u64, bounded_secret(secret)));
+
+        run_to_block(20);
+
+        // More IDs than `MaxSweep` don't decode
+        let mut oversized = RuntimeCall::Fusion(crate::Call::sweep_expired { escrow_ids: Default::default() }).encode();
+        oversized.pop();
+        oversized.extend(vec![0u64, 1, 2, 3, 4].encode());
+        assert!(RuntimeCall::decode(&mut &oversized[..]).is_err());
+
+        assert_ok!(Fusion::sweep_expired(RuntimeOrigin::signed(CHARLIE), vec![0, 2, 3, 1].try_into().unwrap()));
+
+        System::assert_last_event(RuntimeEvent::Fusion(Event::ExpiredEscrowsSwept {
+            refunded: vec![0, 1],
+            skipped: vec![2, 3],
+        }));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Cancelled);
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().state, EscrowState::Cancelled);
+        assert_eq!(Fusion::get_escrow(&2u64).unwrap().state, EscrowState::Active);
+        assert_eq!(Fusion::get_escrow(&3u64).unwrap().state, EscrowState::Completed);
+        // Two refunds back; the live escrow and the completed one stay out of Alice's balance
+        assert_eq!(Balances::free_balance(ALICE), alice_initial - 2000);
+    });
+}
+
+// ===== Alternative recipient tests =====
+
+#[test]
+fn complete_escrow_to_pays_allowed_recipient() {
+    new_test_ext().execute_with(|| {
+        let secret = b"recipient_secret";
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"settlement".to_vec(),
+            None,
+            EscrowOptions { allowed_recipients: vec![DAVE].try_into().unwrap(), ..Default::default() },
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        let bob_before = Balances::free_balance(BOB);
+        assert_ok!(Fusion::complete_escrow_to(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret), DAVE, 1000));
+
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Balances::free_balance(DAVE), 1000);
+        assert_eq!(Balances::free_balance(BOB), bob_before);
+    });
+}
+
+#[test]
+fn complete_escrow_to_rejects_unlisted_recipient() {
+    new_test_ext().execute_with(|| {
+        let secret = b"recipient_secret";
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"settlement".to_vec(),
+            None,
+            EscrowOptions { allowed_recipients: vec![DAVE].try_into().unwrap(), ..Default::default() },
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::complete_escrow_to(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret), CHARLIE, 0),
+            Error::<Test>::RecipientNotAllowed
+        );
+    });
+}
+
+// ===== Pagination tests =====
+
+#[test]
+fn escrows_paginated_pages_through_every_escrow() {
+    new_test_ext().execute_with(|| {
+        for hashlock in [b"page_0", b"page_1", b"page_2", b"page_3", b"page_4"] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"paged".to_vec(),
+                None,
+            ));
+        }
+
+        let mut seen = Vec::new();
+        let mut page_sizes = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, next) = Fusion::escrows_paginated(cursor, 2);
+            page_sizes.push(page.len());
+            seen.extend(page.into_iter().map(|(id, escrow)| {
+                assert_eq!(escrow.id, id);
+                id
+            }));
+            match next {
+                Some(_) => cursor = next,
+                None => break,
+            }
+        }
+
+        assert_eq!(page_sizes, vec![2, 2, 1]);
+        seen.sort();
+        assert_eq!(seen, vec![0u64, 1, 2, 3, 4]);
+    });
+}
+
+// ===== Timestamp deadline tests =====
+
+fn create_with_deadline(secret: &[u8], deadline: u64) -> frame_support::dispatch::DispatchResult {
+    Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        sp_core::hashing::sha2_256(secret).to_vec(),
+        100u64,
+        b"eth aligned".to_vec(),
+        None,
+        EscrowOptions { timelock_timestamp: Some(deadline), ..Default::default() },
+    )
+}
+
+#[test]
+fn timestamp_deadline_blocks_completion_before_block_timelock() {
+    new_test_ext().execute_with(|| {
+        let secret = b"deadline_secret";
+        // Genesis clock sits at 12s; blocks advance it by 6s each
+        assert_ok!(create_with_deadline(secret, 60));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        run_to_block(10);
+        assert_eq!(Fusion::now_unix_secs(), 60);
+        assert!(Fusion::is_timelock_expired(&0u64));
+
+        // Block timelock (101) is far off, but the Unix deadline has passed
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)),
+            Error::<Test>::TimelockExpired
+        );
+
+        // The refund waits out the safety gap after the deadline, for the creator as well
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"too early")),
+            Error::<Test>::InvalidTimelock
+        );
+        assert_noop!(
+            Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64),
+            Error::<Test>::InvalidTimelock
+        );
+        run_to_block(15);
+        assert_eq!(Fusion::now_unix_secs(), 60 + CancelSafetyGapSecs::get());
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+#[test]
+fn timestamp_deadline_must_be_in_the_future() {
+    new_test_ext().execute_with(|| {
+        run_to_block(5);
+        assert_noop!(create_with_deadline(b"stale_deadline", 30), Error::<Test>::InvalidTimelock);
+
+        assert_ok!(create_with_deadline(b"fresh_deadline", 31));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(b"fresh_deadline")));
+    });
+}
+
+// ===== Escrow ID exhaustion tests =====
+
+#[test]
+fn exhausted_escrow_ids_error_instead_of_overwriting() {
+    new_test_ext().execute_with(|| {
+        crate::NextEscrowId::<Test>::put(u64::MAX - 1);
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"last_id".to_vec(),
+            100u64,
+            b"last".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::next_escrow_id(), u64::MAX);
+
+        assert_noop!(
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(CHARLIE),
+                BOB,
+                AssetInfo::Native,
+                2000u128,
+                b"one_too_many".to_vec(),
+                100u64,
+                b"overflow".to_vec(),
+                None,
+            ),
+            Error::<Test>::EscrowIdExhausted
+        );
+        assert_eq!(Fusion::get_escrow(&(u64::MAX - 1)).unwrap().creator, ALICE);
+    });
+}
+
+// ===== Asset allowlist tests =====
+
+#[test]
+fn allowlist_restricts_creation_once_populated() {
+    new_test_ext().execute_with(|| {
+        // Empty list defers to AllowAllWhenUnlisted
+        assert!(Fusion::is_asset_allowed(&AssetInfo::Asset(7u32)));
+
+        assert_noop!(
+            Fusion::set_allowed_asset(RuntimeOrigin::signed(ALICE), AssetKey::Native, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Fusion::set_allowed_asset(RuntimeOrigin::root(), AssetKey::Native, true));
+        System::assert_last_event(RuntimeEvent::Fusion(Event::AllowedAssetSet {
+            asset: AssetKey::Native,
+            allowed: true,
+        }));
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"listed_hash".to_vec(),
+            100u64,
+            b"listed".to_vec(),
+            None,
+        ));
+        assert_noop!(
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Asset(7u32),
+                1000u128,
+                b"unlisted_hash".to_vec(),
+                100u64,
+                b"unlisted".to_vec(),
+                None,
+            ),
+            Error::<Test>::AssetNotAllowed
+        );
+
+        // Removing the last entry lifts the restriction again
+        assert_ok!(Fusion::set_allowed_asset(RuntimeOrigin::root(), AssetKey::Native, false));
+        assert!(Fusion::is_asset_allowed(&AssetInfo::Asset(7u32)));
+    });
+}
+
+// ===== Lifecycle counter tests =====
+
+fn counters() -> (u64, u64, u64, u64) {
+    (Fusion::total_created(), Fusion::total_active(), Fusion::total_completed(), Fusion::total_cancelled())
+}
+
+#[test]
+fn lifecycle_counters_track_every_transition() {
+    new_test_ext().execute_with(|| {
+        let secret = b"counter_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            10u64,
+            b"completes".to_vec(),
+            None,
+        ));
+        assert_eq!(counters(), (1, 0, 0, 0));
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(counters(), (1, 1, 0, 0));
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(counters(), (1, 0, 1, 0));
+
+        // Abandoned before funding: never counted as active
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"counter_abandoned".to_vec(),
+            10u64,
+            b"abandoned".to_vec(),
+            None,
+        ));
+        assert_eq!(counters(), (2, 0, 1, 0));
+        assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 1u64));
+        assert_eq!(counters(), (2, 0, 1, 1));
+
+        // Funded then refunded after expiry
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"counter_refunded".to_vec(),
+            10u64,
+            b"refunded".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 2u64));
+        assert_eq!(counters(), (3, 1, 1, 1));
+
+        run_to_block(20);
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 2u64));
+        assert_eq!(counters(), (3, 0, 1, 2));
+    });
+}
+
+// ===== Cancel safety gap tests =====
+
+#[test]
+fn neither_claim_nor_refund_within_safety_gap() {
+    new_test_ext().execute_with(|| {
+        let secret = b"gap_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            10u64,
+            b"gap".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let timelock = Fusion::get_escrow(&0u64).unwrap().timelock;
+
+        run_to_block(timelock);
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)),
+            Error::<Test>::TimelockExpired
+        );
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(CHARLIE), 0u64, cancel_reason(b"too early")),
+            Error::<Test>::InvalidTimelock
+        );
+        assert_noop!(
+            Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64),
+            Error::<Test>::InvalidTimelock
+        );
+        // The gap binds the creator too
+        run_to_block(timelock + CancelSafetyGap::get() - 1);
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"too early")),
+            Error::<Test>::InvalidTimelock
+        );
+
+        run_to_block(timelock + CancelSafetyGap::get());
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+    });
+}
+
+// ===== Batch completion tests =====
+
+#[test]
+fn batch_completion_keeps_good_items_when_others_fail() {
+    new_test_ext().execute_with(|| {
+        let secrets: [&[u8]; 3] = [b"batch_secret_0", b"batch_secret_1", b"batch_secret_2"];
+        for secret in secrets {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                sp_core::hashing::sha2_256(secret).to_vec(),
+                100u64,
+                b"batch".to_vec(),
+                None,
+            ));
+        }
+        for escrow_id in 0u64..3 {
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id));
+        }
+
+        // More items than `MaxBatchCompletions` don't decode
+        let call = crate::Call::complete_escrow_batch { items: Default::default() };
+        let mut oversized = RuntimeCall::Fusion(call).encode();
+        oversized.pop();
+        oversized.extend(vec![(0u64, bounded_secret(b"")); 5].encode());
+        assert!(RuntimeCall::decode(&mut &oversized[..]).is_err());
+
+        let bob_before = Balances::free_balance(BOB);
+        assert_ok!(Fusion::complete_escrow_batch(
+            RuntimeOrigin::signed(BOB),
+            vec![
+                (0, bounded_secret(secrets[0])),
+                (1, bounded_secret(b"wrong_secret")),
+                (2, bounded_secret(secrets[2])),
+                (9, bounded_secret(secrets[1])),
+            ]
+            .try_into()
+            .unwrap(),
+        ));
+
+        System::assert_last_event(RuntimeEvent::Fusion(Event::BatchCompletionResult {
+            completed: vec![0, 2],
+            failed: vec![1, 9],
+        }));
+        assert_eq!(Balances::free_balance(BOB), bob_before + 2000);
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().state, EscrowState::Active);
+    });
+}
+
+// ===== Lifecycle hook tests =====
+
+#[test]
+fn lifecycle_hooks_fire_on_fund_complete_and_cancel() {
+    new_test_ext().execute_with(|| {
+        EscrowHookCalls::set(vec![]);
+        let secret = b"hook_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"hooked".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"hook_abandoned".to_vec(),
+            100u64,
+            b"hooked".to_vec(),
+            None,
+        ));
+        assert!(EscrowHookCalls::get().is_empty());
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 1u64));
+
+        assert_eq!(EscrowHookCalls::get(), vec![("funded", 0), ("completed", 0), ("cancelled", 1)]);
+    });
+}
+
+// ===== Hash algorithm tag tests =====
+
+fn tagged_hashlock(algorithm: HashAlgorithm, secret: &[u8]) -> Vec<u8> {
+    let mut hashlock = vec![algorithm.tag()];
+    hashlock.extend_from_slice(&algorithm.hash(secret));
+    hashlock
+}
+
+#[test]
+fn tagged_hashlocks_verify_with_their_algorithm() {
+    new_test_ext().execute_with(|| {
+        let secret = b"tagged_secret";
+        for (escrow_id, algorithm) in HashAlgorithm::ALL.into_iter().enumerate() {
+            let escrow_id = escrow_id as u64;
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                tagged_hashlock(algorithm, secret),
+                100u64,
+                b"tagged".to_vec(),
+                None,
+            ));
+            let escrow = Fusion::get_escrow(&escrow_id).unwrap();
+            assert_eq!(escrow.hash_algorithm, algorithm);
+            assert_eq!(escrow.hashlock.to_vec(), algorithm.hash(secret).to_vec());
+
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id));
+            assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), escrow_id, bounded_secret(secret)));
+            assert_eq!(Fusion::get_escrow(&escrow_id).unwrap().state, EscrowState::Completed);
+        }
+    });
+}
+
+#[test]
+fn untagged_hashlocks_default_to_sha256() {
+    new_test_ext().execute_with(|| {
+        let secret = b"untagged_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::blake2_256(secret).to_vec(),
+            100u64,
+            b"untagged".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().hash_algorithm, HashAlgorithm::Sha256);
+
+        // A Blake2 hash without its tag is checked as SHA-256, so the secret doesn't open it
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Active);
+
+        // Unknown tags are rejected outright
+        let mut bad = vec![0xff];
+        bad.extend_from_slice(&sp_core::hashing::sha2_256(secret));
+        assert_noop!(
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                bad,
+                100u64,
+                b"bad tag".to_vec(),
+                None,
+            ),
+            Error::<Test>::InvalidHashlock
+        );
+    });
+}
+
+// ===== NFT claimant tests =====
+
+#[test]
+fn nft_payout_restricted_to_listed_claimants() {
+    new_test_ext().execute_with(|| {
+        let secret = b"nft_claim_secret";
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1u32.into(), ALICE, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1u32.into(), ALICE, 1));
+
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Nft { collection_id: 1u32, item_id: 7, metadata: Default::default() },
+            1u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"vetted claim".to_vec(),
+            None,
+            EscrowOptions {
+                allowed_recipients: vec![CHARLIE, DAVE].try_into().unwrap(),
+                nft_claimants: vec![CHARLIE].try_into().unwrap(),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // The secret alone isn't enough to pull the item to an unvetted account
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)),
+            Error::<Test>::BeneficiaryNotAllowed
+        );
+        assert_noop!(
+            Fusion::complete_escrow_to(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret), DAVE, 0),
+            Error::<Test>::BeneficiaryNotAllowed
+        );
+
+        assert_ok!(Fusion::complete_escrow_to(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret), CHARLIE, 1));
+        assert_eq!(Assets::balance(1, CHARLIE), 1);
+    });
+}
+
+// ===== Hashlock rotation tests =====
+
+#[test]
+fn rotate_secret_hash_rekeys_index_before_funding() {
+    new_test_ext().execute_with(|| {
+        let old_secret = b"old_secret";
+        let new_secret = b"new_secret";
+        let old_hashlock = sp_core::hashing::sha2_256(old_secret).to_vec();
+        let new_hashlock = sp_core::hashing::sha2_256(new_secret).to_vec();
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            old_hashlock.clone(),
+            100u64,
+            b"rotating".to_vec(),
+            None,
+        ));
+
+        assert_noop!(
+            Fusion::rotate_secret_hash(RuntimeOrigin::signed(BOB), 0u64, new_hashlock.clone()),
+            Error::<Test>::NotCreator
+        );
+        assert_ok!(Fusion::rotate_secret_hash(RuntimeOrigin::signed(ALICE), 0u64, new_hashlock.clone()));
+
+        let old_key: crate::BoundedVec<u8, _> = old_hashlock.try_into().unwrap();
+        let new_key: crate::BoundedVec<u8, _> = new_hashlock.clone().try_into().unwrap();
+        assert!(!crate::EscrowsByHashlock::<Test>::contains_key(&old_key, 0u64));
+        assert!(crate::EscrowsByHashlock::<Test>::contains_key(&new_key, 0u64));
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_noop!(
+            Fusion::rotate_secret_hash(RuntimeOrigin::signed(ALICE), 0u64, new_hashlock),
+            Error::<Test>::InvalidEscrowState
+        );
+
+        // The old preimage no longer opens it
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(old_secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Active);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(new_secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+    });
+}
+
+// ===== Slippage protection tests =====
+
+#[test]
+fn fee_pushing_payout_below_minimum_is_rejected() {
+    new_test_ext().execute_with(|| {
+        ProtocolFeeBps::set(100);
+        let secret = b"slippage_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"slippage".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // A 1% fee leaves 990 for the recipient
+        assert_noop!(
+            Fusion::complete_escrow_to(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret), BOB, 1000),
+            Error::<Test>::SlippageExceeded
+        );
+
+        let bob_before = Balances::free_balance(BOB);
+        let collector_before = Balances::free_balance(FEE_COLLECTOR);
+        assert_ok!(Fusion::complete_escrow_to(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret), BOB, 990));
+        assert_eq!(Balances::free_balance(BOB), bob_before + 990);
+        assert_eq!(Balances::free_balance(FEE_COLLECTOR), collector_before + 10);
+        System::assert_has_event(RuntimeEvent::Fusion(Event::ProtocolFeeCharged { escrow_id: 0, amount: 10 }));
+    });
+}
+
+// ===== Mutual cancel tests =====
+
+#[test]
+fn mutual_cancel_refunds_creator_before_expiry() {
+    new_test_ext().execute_with(|| {
+        let alice_initial = Balances::free_balance(ALICE);
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"abort_hash".to_vec(),
+            100u64,
+            b"abort".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        let sig = TestSignature(ALICE, (MUTUAL_CANCEL_CONTEXT, 0u64).encode());
+        assert_noop!(
+            Fusion::mutual_cancel(RuntimeOrigin::signed(CHARLIE), 0u64, sig.clone()),
+            Error::<Test>::NotBeneficiary
+        );
+        assert_ok!(Fusion::mutual_cancel(RuntimeOrigin::signed(BOB), 0u64, sig));
+
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Cancelled);
+        assert_eq!(Balances::free_balance(ALICE), alice_initial);
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::EscrowCancelled { cause: CancelReason::MutualAbort, .. })
+        ));
+    });
+}
+
+#[test]
+fn mutual_cancel_rejects_invalid_creator_signature() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"abort_hash".to_vec(),
+            100u64,
+            b"abort".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // Signed by the beneficiary instead of the creator
+        let forged = TestSignature(BOB, (MUTUAL_CANCEL_CONTEXT, 0u64).encode());
+        assert_noop!(
+            Fusion::mutual_cancel(RuntimeOrigin::signed(BOB), 0u64, forged),
+            Error::<Test>::InvalidAuthorization
+        );
+        // Signed for a different escrow
+        let wrong_escrow = TestSignature(ALICE, (MUTUAL_CANCEL_CONTEXT, 1u64).encode());
+        assert_noop!(
+            Fusion::mutual_cancel(RuntimeOrigin::signed(BOB), 0u64, wrong_escrow),
+            Error::<Test>::InvalidAuthorization
+        );
+    });
+}
+
+// ===== Global capacity tests =====
+
+#[test]
+fn global_active_cap_spans_accounts() {
+    new_test_ext().execute_with(|| {
+        MaxGlobalActiveEscrows::set(2);
+        for (creator, hashlock) in [(ALICE, b"global_0"), (BOB, b"global_1"), (CHARLIE, b"global_2")] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(creator),
+                DAVE,
+                AssetInfo::Native,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"global".to_vec(),
+                None,
+            ));
+        }
+
+        // Unfunded escrows don't use up the budget
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(BOB), 1u64));
+        assert_noop!(
+            Fusion::fund_escrow(RuntimeOrigin::signed(CHARLIE), 2u64),
+            Error::<Test>::GlobalCapacityReached
+        );
+
+        // Settling one frees a slot
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"make room")));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(CHARLIE), 2u64));
+        assert_eq!(Fusion::total_active(), 2);
+    });
+}
+
+// ===== Solvency tests =====
+
+#[test]
+fn reconcile_flags_underfunded_escrow_accounts() {
+    new_test_ext().execute_with(|| {
+        for (creator, hashlock) in [(ALICE, b"solvent_0"), (BOB, b"solvent_1")] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(creator),
+                DAVE,
+                AssetInfo::Native,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"solvency".to_vec(),
+                None,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(BOB), 1u64));
+        assert_ok!(Fusion::reconcile());
+
+        // Record more than the escrow account actually received
+        crate::Escrows::<Test>::mutate(1u64, |escrow| escrow.as_mut().unwrap().amount = 1500);
+        let shortfalls = Fusion::reconcile().unwrap_err();
+        assert_eq!(shortfalls.len(), 1);
+        assert_eq!(shortfalls[0].asset, AssetKey::Native);
+        assert_eq!(shortfalls[0].expected, 2500);
+        assert!(shortfalls[0].actual < shortfalls[0].expected);
+
+        assert_noop!(Fusion::verify_solvency(RuntimeOrigin::signed(ALICE)), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(Fusion::verify_solvency(RuntimeOrigin::root()));
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::SolvencyChecked { asset: AssetKey::Native, expected: 2500, .. })
+        ));
+    });
+}
+
+// ===== Escrow account isolation tests =====
+
+#[test]
+fn each_escrow_holds_funds_in_its_own_account() {
+    new_test_ext().execute_with(|| {
+        let secret = b"isolated_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"isolated".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(CHARLIE),
+            DAVE,
+            AssetInfo::Native,
+            2000u128,
+            b"other_hash".to_vec(),
+            100u64,
+            b"isolated".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(CHARLIE), 1u64));
+
+        let (first, second) = (Fusion::escrow_account(&0u64), Fusion::escrow_account(&1u64));
+        assert_ne!(first, second);
+        assert_eq!(Balances::free_balance(first), 1000);
+        assert_eq!(Balances::free_balance(second), 2000);
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Balances::free_balance(first), 0);
+        assert_eq!(Balances::free_balance(second), 2000);
+    });
+}
+
+// ===== Cross-asset payout tests =====
+
+#[test]
+fn cross_asset_payout_converts_at_oracle_rate() {
+    new_test_ext().execute_with(|| {
+        let usdc = 7u32;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), usdc.into(), ALICE, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), usdc.into(), CONVERSION_POOL, 1_000_000));
+
+        let secret = b"convert_secret";
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"convert".to_vec(),
+            None,
+            EscrowOptions { payout_asset: Some(AssetKey::Asset(usdc)), ..Default::default() },
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // Two units out per unit in
+        assert_noop!(
+            Fusion::complete_escrow_to(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret), BOB, 2001),
+            Error::<Test>::SlippageExceeded
+        );
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+
+        assert_eq!(Assets::balance(usdc, BOB), 2000);
+        assert_eq!(Assets::balance(usdc, CONVERSION_POOL), 998_000);
+        assert_eq!(Balances::free_balance(CONVERSION_POOL), 1000);
+    });
+}
+
+#[test]
+fn cross_asset_payout_requires_a_price() {
+    new_test_ext().execute_with(|| {
+        OracleRate::set(None);
+        let secret = b"no_price_secret";
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"convert".to_vec(),
+            None,
+            EscrowOptions { payout_asset: Some(AssetKey::Asset(7)), ..Default::default() },
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)),
+            Error::<Test>::PriceUnavailable
+        );
+    });
+}
+
+// ===== Lifecycle event timing tests =====
+
+#[test]
+fn lifecycle_events_carry_block_and_time() {
+    new_test_ext().execute_with(|| {
+        let secret = b"timed_secret";
+        let at_time = || Timestamp::get() / 1000;
+
+        run_to_block(3);
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"timed".to_vec(),
+            None,
+        ));
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::EscrowCreated { at_block: 3, at_time: t, .. }) if t == at_time()
+        ));
+
+        run_to_block(5);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::EscrowFunded { at_block: 5, at_time: t, .. }) if t == at_time()
+        ));
+
+        run_to_block(8);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::EscrowCompleted { at_block, .. }) if at_block == System::block_number()
+        ));
+
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"timed_cancel".to_vec(),
+            100u64,
+            b"timed".to_vec(),
+            None,
+        ));
+        run_to_block(13);
+        assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 1u64));
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::EscrowCancelled { at_block: 13, at_time: t, .. }) if t == at_time()
+        ));
+    });
+}
+
+// ===== Dispute window tests =====
+
+fn create_disputable_escrow() {
+    assert_ok!(Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        b"disputed_hash".to_vec(),
+        100u64,
+        b"disputed".to_vec(),
+        None,
+        EscrowOptions { dispute_window: Some(20), ..Default::default() },
+    ));
+    assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+}
+
+#[test]
+fn expired_cancel_holds_refund_until_dispute_window_passes() {
+    new_test_ext().execute_with(|| {
+        let alice_initial = Balances::free_balance(ALICE);
+        create_disputable_escrow();
+
+        run_to_block(106);
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"expired")));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Disputable);
+        assert_eq!(Fusion::escrow_status(&0u64), EscrowStatus::Disputable { finalize_at: 126 });
+        assert_eq!(Balances::free_balance(ALICE), alice_initial - 1000);
+
+        assert_noop!(
+            Fusion::finalize_refund(RuntimeOrigin::signed(CHARLIE), 0u64),
+            Error::<Test>::DisputeWindowOpen
+        );
+
+        run_to_block(126);
+        assert_ok!(Fusion::finalize_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Cancelled);
+        assert_eq!(Balances::free_balance(ALICE), alice_initial);
+        assert_eq!(Fusion::total_active(), 0);
+        assert!(Fusion::refund_holds(&0u64).is_none());
+    });
+}
+
+#[test]
+fn raised_dispute_blocks_refund_finalization() {
+    new_test_ext().execute_with(|| {
+        create_disputable_escrow();
+
+        run_to_block(106);
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Disputable);
+
+        assert_noop!(Fusion::raise_dispute(RuntimeOrigin::signed(ALICE), 0u64), Error::<Test>::NotBeneficiary);
+        assert_ok!(Fusion::raise_dispute(RuntimeOrigin::signed(BOB), 0u64));
+        assert!(Fusion::get_escrow(&0u64).unwrap().frozen);
+
+        run_to_block(130);
+        assert_noop!(Fusion::finalize_refund(RuntimeOrigin::signed(ALICE), 0u64), Error::<Test>::EscrowFrozen);
+
+        // Arbitration releases the escrow and the refund goes through
+        assert_ok!(Fusion::unfreeze_escrow(RuntimeOrigin::root(), 0u64));
+        assert_ok!(Fusion::finalize_refund(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+// ===== Bridged asset tests =====
+
+#[test]
+fn bridged_asset_completion_burns_and_mints() {
+    new_test_ext().execute_with(|| {
+        let wrapped = 9u32;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), wrapped.into(), ALICE, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), wrapped.into(), ALICE, 10_000));
+
+        let secret = b"bridged_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Bridged(wrapped),
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"bridged".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let escrow_account = Fusion::escrow_account(&0u64);
+        assert_eq!(Assets::balance(wrapped, escrow_account), 1000);
+        assert_eq!(Assets::total_supply(wrapped), 10_000);
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Assets::balance(wrapped, escrow_account), 0);
+        assert_eq!(Assets::balance(wrapped, BOB), 1000);
+        assert_eq!(Assets::total_supply(wrapped), 10_000);
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Assets(pallet_assets::Event::Burned { asset_id: 9, owner, balance: 1000 }) if owner == escrow_account
+        )));
+    });
+}
+
+// ===== Pause origin tests =====
+
+#[test]
+fn only_pause_origin_can_pause() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(Fusion::emergency_pause(RuntimeOrigin::signed(ALICE)), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(
+            Fusion::set_pause_flags(RuntimeOrigin::signed(ALICE), PauseFlags::emergency()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Fusion::emergency_pause(RuntimeOrigin::signed(PAUSE_GUARDIAN)));
+        assert!(Fusion::emergency_paused());
+        assert_noop!(Fusion::emergency_unpause(RuntimeOrigin::signed(ALICE)), sp_runtime::DispatchError::BadOrigin);
+
+        assert_ok!(Fusion::emergency_unpause(RuntimeOrigin::root()));
+        assert!(!Fusion::emergency_paused());
+    });
+}
+
+// ===== Fee withdrawal tests =====
+
+#[test]
+fn accumulated_fees_can_be_withdrawn() {
+    new_test_ext().execute_with(|| {
+        ProtocolFeeBps::set(100);
+        for (escrow_id, secret) in [(0u64, b"fees_secret_0"), (1u64, b"fees_secret_1")] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                100_000u128,
+                sp_core::hashing::sha2_256(secret).to_vec(),
+                100u64,
+                b"fees".to_vec(),
+                None,
+            ));
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id));
+            assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), escrow_id, bounded_secret(secret)));
+        }
+        assert_eq!(Fusion::accumulated_fees(AssetKey::Native), 2000);
+
+        assert_noop!(
+            Fusion::withdraw_fees(RuntimeOrigin::signed(ALICE), AssetKey::Native, 2000, CHARLIE),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Fusion::withdraw_fees(RuntimeOrigin::root(), AssetKey::Native, 2001, CHARLIE),
+            Error::<Test>::InsufficientAccumulatedFees
+        );
+
+        let charlie_before = Balances::free_balance(CHARLIE);
+        assert_ok!(Fusion::withdraw_fees(RuntimeOrigin::root(), AssetKey::Native, 2000, CHARLIE));
+        assert_eq!(Balances::free_balance(CHARLIE), charlie_before + 2000);
+        assert_eq!(Fusion::accumulated_fees(AssetKey::Native), 0);
+        System::assert_last_event(RuntimeEvent::Fusion(Event::FeesWithdrawn {
+            asset: AssetKey::Native,
+            amount: 2000,
+            dest: CHARLIE,
+        }));
+    });
+}
+
+// ===== Escrow template tests =====
+
+#[test]
+fn escrow_created_from_template_uses_its_terms() {
+    new_test_ext().execute_with(|| {
+        let params = TemplateParams {
+            beneficiary: BOB,
+            asset: AssetInfo::Native,
+            timelock_duration: 150u64,
+            metadata: b"maker template".to_vec(),
+            xcm_route: None,
+        };
+        assert_ok!(Fusion::register_template(RuntimeOrigin::signed(ALICE), params));
+        System::assert_last_event(RuntimeEvent::Fusion(Event::TemplateRegistered { template_id: 0, owner: ALICE }));
+
+        assert_noop!(
+            Fusion::create_from_template(RuntimeOrigin::signed(CHARLIE), 0, b"tpl_hash_0".to_vec(), 1000),
+            Error::<Test>::NotTemplateOwner
+        );
+        assert_noop!(
+            Fusion::create_from_template(RuntimeOrigin::signed(ALICE), 1, b"tpl_hash_0".to_vec(), 1000),
+            Error::<Test>::TemplateNotFound
+        );
+        assert_ok!(Fusion::create_from_template(RuntimeOrigin::signed(ALICE), 0, b"tpl_hash_0".to_vec(), 2500));
+
+        let escrow = Fusion::get_escrow(&0u64).unwrap();
+        assert_eq!(escrow.creator, ALICE);
+        assert_eq!(escrow.beneficiary, BOB);
+        assert_eq!(escrow.asset, AssetInfo::Native);
+        assert_eq!(escrow.amount, 2500);
+        assert_eq!(escrow.timelock, 1 + 150);
+        assert_eq!(escrow.metadata.to_vec(), b"maker template".to_vec());
+        assert_eq!(escrow.hashlock.to_vec(), b"tpl_hash_0".to_vec());
+    });
+}
+
+// ===== Emergency withdrawal tests =====
+
+#[test]
+fn emergency_withdraw_recovers_where_cancel_fails() {
+    new_test_ext().execute_with(|| {
+        let asset_id = 11u32;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id.into(), ALICE, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), asset_id.into(), ALICE, 10_000));
+        for (asset, hashlock) in [(AssetInfo::Asset(asset_id), b"stuck_asset"), (AssetInfo::Native, b"stuck_native")] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                asset,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"stuck".to_vec(),
+                None,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+
+        // The asset can no longer move, so the normal refund path is stuck
+        assert_ok!(Assets::freeze_asset(RuntimeOrigin::signed(ALICE), asset_id.into()));
+        assert!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"stuck")).is_err());
+
+        assert_noop!(
+            Fusion::emergency_withdraw(RuntimeOrigin::signed(ALICE), 0u64, CHARLIE),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Fusion::emergency_withdraw(RuntimeOrigin::root(), 0u64, CHARLIE));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Cancelled);
+        System::assert_has_event(RuntimeEvent::Fusion(Event::EmergencyWithdrawn {
+            escrow_id: 0,
+            dest: CHARLIE,
+            recovered: 0,
+        }));
+
+        // Whatever is still movable is recovered in full
+        let charlie_before = Balances::free_balance(CHARLIE);
+        assert_ok!(Fusion::emergency_withdraw(RuntimeOrigin::root(), 1u64, CHARLIE));
+        assert_eq!(Balances::free_balance(CHARLIE), charlie_before + 1000);
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().state, EscrowState::Cancelled);
+        assert_eq!(Fusion::total_active(), 0);
+    });
+}
+
+// ===== Escrow account salt tests =====
+
+#[test]
+fn escrow_accounts_are_salted_and_stable() {
+    new_test_ext().execute_with(|| {
+        for hashlock in [b"salted_0", b"salted_1"] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"salted".to_vec(),
+                None,
+            ));
+        }
+
+        let (first, second) = (Fusion::escrow_account(&0u64), Fusion::escrow_account(&1u64));
+        assert_ne!(first, second);
+        assert_ne!(Fusion::escrow_salts(&0u64), Fusion::escrow_salts(&1u64));
+        // Not the sequential sub-account the ID alone would give
+        let unsalted: u64 = FusionPalletId::get().into_sub_account_truncating(0u64);
+        assert_ne!(first, unsalted);
+
+        run_to_block(5);
+        assert_eq!(Fusion::escrow_account(&0u64), first);
+        assert_eq!(Fusion::escrow_account(&1u64), second);
+    });
+}
+
+// ===== Hash round tests =====
+
+fn create_escrow_with_rounds(hashlock: Vec<u8>, hash_rounds: u8) -> sp_runtime::DispatchResult {
+    Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        hashlock,
+        100u64,
+        b"rounds".to_vec(),
+        None,
+        EscrowOptions { hash_rounds, ..Default::default() },
+    )
+}
+
+#[test]
+fn single_round_escrow_completes_with_preimage() {
+    new_test_ext().execute_with(|| {
+        let secret = b"single_round";
+        assert_ok!(create_escrow_with_rounds(sp_core::hashing::sha2_256(secret).to_vec(), 1));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+    });
+}
+
+#[test]
+fn double_round_escrow_needs_the_original_secret() {
+    new_test_ext().execute_with(|| {
+        let secret = b"double_round";
+        let inner = sp_core::hashing::sha2_256(secret);
+        assert_ok!(create_escrow_with_rounds(sp_core::hashing::sha2_256(&inner).to_vec(), 2));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // The single-round preimage of the hashlock is not accepted
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(&inner)),
+            Error::<Test>::IncorrectSecret
+        );
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+    });
+}
+
+#[test]
+fn zero_hash_rounds_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(create_escrow_with_rounds(b"zero_rounds".to_vec(), 0), Error::<Test>::InvalidHashRounds);
+    });
+}
+
+// ===== Metadata limit tests =====
+
+fn create_escrow_with_metadata(hashlock: &[u8], metadata: Vec<u8>) -> sp_runtime::DispatchResult {
+    Fusion::create_escrow(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        hashlock.to_vec(),
+        100u64,
+        metadata,
+        None,
+    )
+}
+
+#[test]
+fn metadata_length_follows_configured_limit() {
+    new_test_ext().execute_with(|| {
+        MaxMetadataLen::set(8);
+        assert_noop!(create_escrow_with_metadata(b"small_limit", vec![1u8; 9]), Error::<Test>::InvalidMetadata);
+        assert_ok!(create_escrow_with_metadata(b"small_limit", vec![1u8; 8]));
+
+        MaxMetadataLen::set(4096);
+        assert_ok!(create_escrow_with_metadata(b"large_limit", vec![1u8; 2048]));
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().metadata.len(), 2048);
+    });
+}
+
+// ===== Taker query tests =====
+
+#[test]
+fn completable_escrows_for_taker_filters_live_escrows() {
+    new_test_ext().execute_with(|| {
+        let escrows: [(&[u8], u64, u64); 5] = [
+            (b"taker_unfunded", BOB, 100),
+            (b"taker_active", BOB, 100),
+            (b"taker_frozen", BOB, 100),
+            (b"taker_expired", BOB, 10),
+            (b"other_taker", DAVE, 100),
+        ];
+        for (escrow_id, (hashlock, taker, duration)) in escrows.iter().enumerate() {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                *taker,
+                AssetInfo::Native,
+                1000u128,
+                hashlock.to_vec(),
+                *duration,
+                b"taker".to_vec(),
+                None,
+            ));
+            if escrow_id > 0 {
+                assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id as u64));
+            }
+        }
+        assert_ok!(Fusion::freeze_escrow(RuntimeOrigin::root(), 2u64));
+
+        assert_eq!(Fusion::completable_escrows_for_taker(&BOB), vec![1u64, 3]);
+        run_to_block(15);
+        assert_eq!(Fusion::completable_escrows_for_taker(&BOB), vec![1u64]);
+        assert_eq!(Fusion::completable_escrows_for_taker(&DAVE), vec![4u64]);
+    });
+}
+
+// ===== Existential deposit tests =====
+
+#[test]
+fn fresh_escrow_account_keeps_amount_at_existential_deposit() {
+    new_test_ext().execute_with(|| {
+        let ed = <Test as pallet_balances::Config>::ExistentialDeposit::get();
+        for (hashlock, amount) in [(b"below_ed", ed - 1), (b"at_ed___", ed)] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                amount,
+                hashlock.to_vec(),
+                100u64,
+                b"ed".to_vec(),
+                None,
+            ));
+        }
+
+        assert_noop!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64), Error::<Test>::BelowExistentialDeposit);
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+        assert_eq!(Balances::free_balance(Fusion::escrow_account(&1u64)), ed);
+        assert_ok!(Fusion::reconcile());
+    });
+}
+
+// ===== Maker settlement tests =====
+
+#[test]
+fn maker_completes_for_taker_after_grace_block() {
+    new_test_ext().execute_with(|| {
+        let secret = b"maker_settles";
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"settle".to_vec(),
+            None,
+            EscrowOptions { maker_complete_after: Some(50), ..Default::default() },
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(ALICE), 0u64, bounded_secret(secret)),
+            Error::<Test>::NotBeneficiary
+        );
+
+        run_to_block(50);
+        let bob_before = Balances::free_balance(BOB);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(ALICE), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Balances::free_balance(BOB), bob_before + 1000);
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::EscrowCompleted { beneficiary: BOB, recipient: BOB, .. })
+        ));
+    });
+}
+
+// ===== Secret retention tests =====
+
+#[test]
+fn revealed_secret_is_queryable_when_retained() {
+    new_test_ext().execute_with(|| {
+        for (escrow_id, secret) in [(0u64, b"forgotten_secret"), (1u64, b"retained_secret_")] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                sp_core::hashing::sha2_256(secret).to_vec(),
+                100u64,
+                b"retention".to_vec(),
+                None,
+            ));
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), escrow_id));
+        }
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(b"forgotten_secret")));
+        assert_eq!(Fusion::get_revealed_secret(&0u64), None);
+
+        assert_noop!(
+            Fusion::set_secret_retention(RuntimeOrigin::signed(ALICE), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Fusion::set_secret_retention(RuntimeOrigin::root(), true));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 1u64, bounded_secret(b"retained_secret_")));
+        assert_eq!(Fusion::get_revealed_secret(&1u64), Some(b"retained_secret_".to_vec()));
+    });
+}
+
+// ===== Asset timelock bound tests =====
+
+#[test]
+fn asset_timelock_bounds_override_global_ones() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Fusion::set_asset_timelock_bounds(RuntimeOrigin::root(), AssetType::Fungible, Some((60, 50))),
+            Error::<Test>::InvalidTimelockBounds
+        );
+        assert_ok!(Fusion::set_asset_timelock_bounds(RuntimeOrigin::root(), AssetType::Fungible, Some((10, 50))));
+
+        // 100 blocks is within the global bounds but over the fungible override
+        let create = |asset: AssetInfo<u32>, hashlock: &[u8]| {
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                asset,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"bounds".to_vec(),
+                None,
+            )
+        };
+        assert_noop!(create(AssetInfo::Asset(1), b"volatile"), Error::<Test>::TimelockTooLong);
+        assert_ok!(create(AssetInfo::Native, b"native"));
+
+        assert_ok!(Fusion::set_asset_timelock_bounds(RuntimeOrigin::root(), AssetType::Fungible, None));
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1u32.into(), ALICE, true, 1));
+        assert_ok!(create(AssetInfo::Asset(1), b"volatile"));
+    });
+}
+
+// ===== Ownership transfer tests =====
+
+#[test]
+fn transfer_escrow_ownership_moves_index_and_refund() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"ownership_hash".to_vec(),
+            100u64,
+            b"ownership".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(
+            Fusion::transfer_escrow_ownership(RuntimeOrigin::signed(ALICE), 0u64, BOB),
+            Error::<Test>::InvalidBeneficiary
+        );
+        assert_noop!(
+            Fusion::transfer_escrow_ownership(RuntimeOrigin::signed(CHARLIE), 0u64, CHARLIE),
+            Error::<Test>::NotCreator
+        );
+
+        assert_ok!(Fusion::transfer_escrow_ownership(RuntimeOrigin::signed(ALICE), 0u64, CHARLIE));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().creator, CHARLIE);
+        assert!(Fusion::account_escrows(ALICE).is_empty());
+        assert_eq!(Fusion::account_escrows(CHARLIE).to_vec(), vec![0u64]);
+
+        // The old creator lost the role; the new one gets the refund
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"mine")),
+            Error::<Test>::InvalidTimelock
+        );
+        let before = Balances::free_balance(CHARLIE);
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(CHARLIE), 0u64, cancel_reason(b"handoff")));
+        assert_eq!(Balances::free_balance(CHARLIE), before + 1000);
+    });
+}
+
+// ===== Granular validation error tests =====
+
+#[test]
+fn create_escrow_reports_specific_validation_failures() {
+    new_test_ext().execute_with(|| {
+        let create = |asset: AssetInfo<u32>, amount: u128, duration: u64| {
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                asset,
+                amount,
+                b"granular".to_vec(),
+                duration,
+                b"granular".to_vec(),
+                None,
+            )
+        };
+
+        assert_noop!(create(AssetInfo::Native, 1000, 9), Error::<Test>::TimelockTooShort);
+        assert_noop!(create(AssetInfo::Native, 1000, 1_000_001), Error::<Test>::TimelockTooLong);
+        assert_noop!(create(AssetInfo::Native, 0, 100), Error::<Test>::AmountZero);
+        assert_noop!(create(AssetInfo::Native, 99, 100), Error::<Test>::AmountBelowMinimum);
+
+        assert_ok!(Fusion::set_allowed_asset(RuntimeOrigin::root(), AssetKey::Native, true));
+        assert_noop!(create(AssetInfo::Asset(1), 1000, 100), Error::<Test>::AssetNotAllowed);
+    });
+}
+
+// ===== Escrow account query tests =====
+
+#[test]
+fn escrow_account_of_matches_where_funds_land() {
+    new_test_ext().execute_with(|| {
+        for id in 0u64..3 {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128 + id as u128,
+                format!("account_of_{}", id).into_bytes(),
+                100u64,
+                b"account_of".to_vec(),
+                None,
+            ));
+            assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), id));
+            assert_eq!(Balances::free_balance(Fusion::escrow_account(&id)), 1000 + id as u128);
+        }
+
+        // IDs without a salt fall back to the plain sub-account
+        let unsalted: u64 = FusionPalletId::get().into_sub_account_truncating(42u64);
+        assert_eq!(Fusion::escrow_account(&42u64), unsalted);
+    });
+}
+
+// ===== Same-block race tests =====
+
+#[test]
+fn second_terminal_call_in_block_sees_terminal_state() {
+    new_test_ext().execute_with(|| {
+        let secret = b"race_secret";
+        for hashlock in [sp_core::hashing::sha2_256(secret).to_vec(), b"race_other".to_vec()] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock,
+                100u64,
+                b"race".to_vec(),
+                None,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+
+        // Completion lands first; the creator's cancel in the same block finds it settled
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"late")),
+            Error::<Test>::InvalidEscrowState
+        );
+
+        // And the other way round
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 1u64, cancel_reason(b"first")));
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 1u64, bounded_secret(b"race_other")),
+            Error::<Test>::InvalidEscrowState
+        );
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Fusion::get_escrow(&1u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+// ===== Reserve-mode funding tests =====
+
+fn create_reserved_escrow(hashlock: Vec<u8>) -> sp_runtime::DispatchResult {
+    Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        hashlock,
+        100u64,
+        b"reserved".to_vec(),
+        None,
+        EscrowOptions { reserve_native: true, ..Default::default() },
+    )
+}
+
+#[test]
+fn reserved_escrow_keeps_funds_on_maker_until_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = b"reserved_secret";
+        assert_ok!(create_reserved_escrow(sp_core::hashing::sha2_256(secret).to_vec()));
+        let (alice_free, bob_free) = (Balances::free_balance(ALICE), Balances::free_balance(BOB));
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Balances::reserved_balance(ALICE), 1000);
+        assert_eq!(Balances::free_balance(ALICE), alice_free - 1000);
+        assert_eq!(Balances::free_balance(Fusion::escrow_account(&0u64)), 0);
+        assert_ok!(Fusion::reconcile());
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), alice_free - 1000);
+        assert_eq!(Balances::free_balance(BOB), bob_free + 1000);
+    });
+}
+
+#[test]
+fn reserved_escrow_unreserves_on_cancel() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create_reserved_escrow(b"reserved_cancel".to_vec()));
+        let alice_free = Balances::free_balance(ALICE);
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Balances::reserved_balance(ALICE), 1000);
+
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"changed mind")));
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::free_balance(ALICE), alice_free);
+
+        // Only native funds can sit in a reserve
+        assert_noop!(
+            Fusion::create_escrow_with_options(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Asset(1),
+                1000u128,
+                b"reserved_asset".to_vec(),
+                100u64,
+                b"reserved".to_vec(),
+                None,
+                EscrowOptions { reserve_native: true, ..Default::default() },
+            ),
+            Error::<Test>::AssetNotSupported
+        );
+    });
+}
+
+// ===== Account escrow pagination tests =====
+
+#[test]
+fn account_escrows_paginate_by_offset() {
+    new_test_ext().execute_with(|| {
+        for i in 0..25u64 {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                format!("page_{}", i).into_bytes(),
+                100u64,
+                b"page".to_vec(),
+                None,
+            ));
+        }
+
+        assert_eq!(Fusion::get_account_escrows_paginated(&ALICE, 0, 10), (0u64..10).collect::<Vec<_>>());
+        assert_eq!(Fusion::get_account_escrows_paginated(&ALICE, 10, 10), (10u64..20).collect::<Vec<_>>());
+        assert_eq!(Fusion::get_account_escrows_paginated(&ALICE, 20, 10), (20u64..25).collect::<Vec<_>>());
+        assert!(Fusion::get_account_escrows_paginated(&ALICE, 30, 10).is_empty());
+        assert_eq!(Fusion::get_account_escrows(&ALICE).len(), 25);
+    });
+}
+
+// ===== Runtime hash algorithm tests =====
+
+#[test]
+fn untagged_hashlocks_follow_runtime_hash_algorithm() {
+    new_test_ext().execute_with(|| {
+        DefaultHashAlgorithm::set(HashAlgorithm::Blake2_256);
+        let secret = b"runtime_hasher_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::blake2_256(secret).to_vec(),
+            100u64,
+            b"blake2 runtime".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().hash_algorithm, HashAlgorithm::Blake2_256);
+        assert!(Fusion::verify_secret(&0u64, secret));
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Completed);
+        DefaultHashAlgorithm::set(HashAlgorithm::Sha256);
+    });
+}
+
+// ===== Completion time tests =====
+
+#[test]
+fn completion_average_moves_toward_observed_durations() {
+    new_test_ext().execute_with(|| {
+        let secrets: [&[u8]; 3] = [b"ema_0", b"ema_1", b"ema_2"];
+        for secret in secrets {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                sp_core::hashing::sha2_256(secret).to_vec(),
+                100u64,
+                b"ema".to_vec(),
+                None,
+            ));
+        }
+        assert_eq!(Fusion::avg_completion_blocks(), None);
+
+        // Funded at 1, completed at 11: the first sample seeds the average
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        run_to_block(11);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secrets[0])));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().funded_at, Some(1));
+        assert_eq!(Fusion::avg_completion_blocks(), Some(10));
+
+        // Two 40-block completions pull it up an eighth of the gap each time
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+        run_to_block(51);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 1u64, bounded_secret(secrets[1])));
+        assert_eq!(Fusion::avg_completion_blocks(), Some(13));
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 2u64));
+        run_to_block(91);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 2u64, bounded_secret(secrets[2])));
+        assert_eq!(Fusion::avg_completion_blocks(), Some(16));
+    });
+}
+
+// ===== Secret length tests =====
+
+#[test]
+fn oversized_secret_does_not_decode() {
+    new_test_ext().execute_with(|| {
+        // Swap the empty trailing secret for a raw one of any length
+        let with_secret = |call: crate::Call<Test>, secret: Vec<u8>| {
+            let mut encoded = RuntimeCall::Fusion(call).encode();
+            encoded.pop();
+            encoded.extend(secret.encode());
+            encoded
+        };
+        let complete = |secret: Vec<u8>| {
+            with_secret(crate::Call::complete_escrow { escrow_id: 0, secret: Default::default() }, secret)
+        };
+        let reveal = |secret: Vec<u8>| with_secret(crate::Call::reveal_secret { secret: Default::default() }, secret);
+
+        let oversized = vec![7u8; MAX_SECRET_SIZE as usize + 1];
+        assert!(RuntimeCall::decode(&mut &complete(oversized.clone())[..]).is_err());
+        assert!(RuntimeCall::decode(&mut &reveal(oversized)[..]).is_err());
+
+        let secret = vec![7u8; MAX_SECRET_SIZE as usize];
+        create_native_escrow(&sp_core::hashing::sha2_256(&secret), 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let call = RuntimeCall::decode(&mut &complete(secret)[..]).unwrap();
+        assert_ok!(call.dispatch(RuntimeOrigin::signed(BOB)));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Completed);
+    });
+}
+
+// ===== Idle pruning tests =====
+
+#[test]
+fn on_idle_prunes_terminal_escrows_after_retention() {
+    use frame_support::{traits::Hooks, weights::Weight};
+    use crate::weights::WeightInfo;
+
+    new_test_ext().execute_with(|| {
+        let secret = b"prune_secret";
+        for hashlock in [sp_core::hashing::sha2_256(secret).to_vec(), b"prune_cancel".to_vec(), b"prune_live".to_vec()] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock,
+                500u64,
+                b"prune".to_vec(),
+                None,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 1u64, cancel_reason(b"prune")));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 2u64));
+
+        // Still within the retention period
+        Fusion::on_idle(50, Weight::MAX);
+        assert!(Fusion::get_escrow(&0u64).is_some());
+        assert!(Fusion::get_escrow(&1u64).is_some());
+
+        // One escrow per idle block, resuming from the cursor each time
+        run_to_block(101);
+        let one_visit = <() as WeightInfo>::prune_escrow();
+        for _ in 0..3 {
+            Fusion::on_idle(101, one_visit);
+        }
+        assert!(Fusion::get_escrow(&0u64).is_none());
+        assert!(Fusion::get_escrow(&1u64).is_none());
+        assert!(Fusion::escrow_salts(&0u64).is_none());
+        assert!(Fusion::escrows_in_state(EscrowState::Completed).is_empty());
+        assert_eq!(Fusion::get_escrow(&2u64).unwrap().state, EscrowState::Active);
+    });
+}
+
+// ===== Taker acceptance tests =====
+
+#[test]
+fn accepted_escrow_can_be_funded() {
+    new_test_ext().execute_with(|| {
+        RequireTakerAcceptance::set(true);
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"accept_hash".to_vec(),
+            100u64,
+            b"accept".to_vec(),
+            None,
+        ));
+
+        assert_noop!(Fusion::accept_escrow(RuntimeOrigin::signed(CHARLIE), 0u64), Error::<Test>::NotBeneficiary);
+        assert_ok!(Fusion::accept_escrow(RuntimeOrigin::signed(BOB), 0u64));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Accepted);
+        assert_eq!(last_event(), RuntimeEvent::Fusion(Event::EscrowAccepted { escrow_id: 0, beneficiary: BOB }));
+
+        // Accepted terms can't be swapped out from under the beneficiary
+        assert_noop!(
+            Fusion::reassign_beneficiary(RuntimeOrigin::signed(ALICE), 0u64, CHARLIE),
+            Error::<Test>::InvalidEscrowState
+        );
+        assert_noop!(Fusion::accept_escrow(RuntimeOrigin::signed(BOB), 0u64), Error::<Test>::InvalidEscrowState);
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Fusion::get_escrow(&0u64).unwrap().state, EscrowState::Active);
+        RequireTakerAcceptance::set(false);
+    });
+}
+
+#[test]
+fn funding_before_acceptance_rejected_when_required() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"unaccepted_hash".to_vec(),
+            100u64,
+            b"unaccepted".to_vec(),
+            None,
+        ));
+
+        RequireTakerAcceptance::set(true);
+        assert_noop!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64), Error::<Test>::NotAccepted);
+
+        // Without the requirement, funding straight from Created still works
+        RequireTakerAcceptance::set(false);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+    });
+}
+
+// ===== Surplus sweep tests =====
+
+#[test]
+fn surplus_at_settled_escrow_returns_to_creator() {
+    new_test_ext().execute_with(|| {
+        let secret = b"surplus_secret";
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(secret).to_vec(),
+            100u64,
+            b"surplus".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // A stray deposit lands on the escrow account on top of the escrowed amount
+        let escrow_account = Fusion::escrow_account(&0u64);
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(CHARLIE), escrow_account, 700));
+        assert_noop!(
+            Fusion::sweep_surplus(RuntimeOrigin::signed(CHARLIE), 0u64),
+            Error::<Test>::InvalidEscrowState
+        );
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Balances::free_balance(escrow_account), 700);
+
+        let alice_before = Balances::free_balance(ALICE);
+        assert_ok!(Fusion::sweep_surplus(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Balances::free_balance(ALICE), alice_before + 700);
+        assert_eq!(Balances::free_balance(escrow_account), 0);
+        assert_noop!(Fusion::sweep_surplus(RuntimeOrigin::signed(CHARLIE), 0u64), Error::<Test>::NoSurplus);
+    });
+}
+
+// ===== Expiry notification tests =====
+
+#[test]
+fn expiry_notice_scheduled_on_funding_and_cancelled_on_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = b"notice_secret";
+        for hashlock in [sp_core::hashing::sha2_256(secret).to_vec(), b"notice_expiring".to_vec()] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock,
+                100u64,
+                b"notice".to_vec(),
+                None,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+        assert_eq!(
+            ScheduledTasks::get(),
+            vec![(Fusion::expiry_task_name(&0u64), 101), (Fusion::expiry_task_name(&1u64), 101)]
+        );
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(ScheduledTasks::get(), vec![(Fusion::expiry_task_name(&1u64), 101)]);
+    });
+}
+
+#[test]
+fn expiry_notice_fires_for_unsettled_escrow() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"notice_hash".to_vec(),
+            100u64,
+            b"notice".to_vec(),
+            None,
+        ));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        // Dispatch the due task the way the scheduler would
+        run_to_block(101);
+        assert_noop!(Fusion::notify_expiry(RuntimeOrigin::signed(ALICE), 0u64), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(Fusion::notify_expiry(RuntimeOrigin::root(), 0u64));
+        assert_eq!(last_event(), RuntimeEvent::Fusion(Event::EscrowNearExpiry { escrow_id: 0 }));
+
+        // Refunding drops the task and later notices stay silent
+        run_to_block(110);
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert!(ScheduledTasks::get().is_empty());
+        let events = System::events().len();
+        assert_ok!(Fusion::notify_expiry(RuntimeOrigin::root(), 0u64));
+        assert_eq!(System::events().len(), events);
+    });
+}
+
+// ===== Pallet summary tests =====
+
+#[test]
+fn pallet_summary_reflects_funding_and_pause() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"summary_hash".to_vec(),
+            100u64,
+            b"summary".to_vec(),
+            None,
+        ));
+        assert_eq!(
+            Fusion::pallet_summary(),
+            PalletSummary { paused: false, total_active: 0, total_completed: 0, tvl_native: 0, next_id: 1 }
+        );
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::emergency_pause(RuntimeOrigin::root()));
+        assert_eq!(
+            Fusion::pallet_summary(),
+            PalletSummary { paused: true, total_active: 1, total_completed: 0, tvl_native: 1000, next_id: 1 }
+        );
+
+        assert_ok!(Fusion::emergency_unpause(RuntimeOrigin::root()));
+        assert!(!Fusion::pallet_summary().paused);
+    });
+}
+
+// ===== Funding approval tests =====
+
+fn create_native_escrow(hashlock: &[u8], amount: u128) {
+    assert_ok!(Fusion::create_escrow(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        amount,
+        hashlock.to_vec(),
+        100u64,
+        b"approval".to_vec(),
+        None,
+    ));
+}
+
+#[test]
+fn approved_agent_funds_from_maker_balance() {
+    new_test_ext().execute_with(|| {
+        create_native_escrow(b"approval_hash_0", 1000);
+        create_native_escrow(b"approval_hash_1", 1000);
+        assert_ok!(Fusion::set_funding_approval(RuntimeOrigin::signed(ALICE), CHARLIE, AssetKey::Native, 2500));
+
+        let alice_before = Balances::free_balance(ALICE);
+        let charlie_before = Balances::free_balance(CHARLIE);
+        assert_ok!(Fusion::fund_escrow_on_behalf(RuntimeOrigin::signed(CHARLIE), 0u64, ALICE));
+        assert_ok!(Fusion::fund_escrow_on_behalf(RuntimeOrigin::signed(CHARLIE), 1u64, ALICE));
+
+        assert_eq!(Balances::free_balance(ALICE), alice_before - 2000);
+        assert_eq!(Balances::free_balance(CHARLIE), charlie_before);
+        assert_eq!(Fusion::escrows(0u64).unwrap().funder, Some(ALICE));
+        assert_eq!(Fusion::funding_approvals(ALICE, (CHARLIE, AssetKey::Native)), 500);
+    });
+}
+
+#[test]
+fn funding_beyond_approval_rejected() {
+    new_test_ext().execute_with(|| {
+        create_native_escrow(b"approval_hash_0", 1000);
+        assert_ok!(Fusion::set_funding_approval(RuntimeOrigin::signed(ALICE), CHARLIE, AssetKey::Native, 999));
+
+        assert_noop!(
+            Fusion::fund_escrow_on_behalf(RuntimeOrigin::signed(CHARLIE), 0u64, ALICE),
+            Error::<Test>::ApprovalExceeded
+        );
+        // No approval from the maker at all
+        assert_noop!(
+            Fusion::fund_escrow_on_behalf(RuntimeOrigin::signed(DAVE), 0u64, ALICE),
+            Error::<Test>::ApprovalExceeded
+        );
+        // Approvals only cover the maker's own escrows
+        assert_ok!(Fusion::set_funding_approval(RuntimeOrigin::signed(BOB), CHARLIE, AssetKey::Native, 5000));
+        assert_noop!(
+            Fusion::fund_escrow_on_behalf(RuntimeOrigin::signed(CHARLIE), 0u64, BOB),
+            Error::<Test>::NotCreator
+        );
+    });
+}
+
+#[test]
+fn revoked_approval_blocks_funding() {
+    new_test_ext().execute_with(|| {
+        create_native_escrow(b"approval_hash_0", 1000);
+        assert_ok!(Fusion::set_funding_approval(RuntimeOrigin::signed(ALICE), CHARLIE, AssetKey::Native, 5000));
+        assert_ok!(Fusion::set_funding_approval(RuntimeOrigin::signed(ALICE), CHARLIE, AssetKey::Native, 0));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::FundingApprovalSet {
+                maker: ALICE,
+                agent: CHARLIE,
+                asset: AssetKey::Native,
+                limit: 0,
+            })
+        );
+
+        assert_noop!(
+            Fusion::fund_escrow_on_behalf(RuntimeOrigin::signed(CHARLIE), 0u64, ALICE),
+            Error::<Test>::ApprovalExceeded
+        );
+    });
+}
+
+// ===== Per-escrow pause tests =====
+
+#[test]
+fn paused_escrow_blocks_settlement_until_resumed() {
+    new_test_ext().execute_with(|| {
+        let secret = b"paused_secret";
+        create_native_escrow(&sp_core::hashing::sha2_256(secret), 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+
+        assert_noop!(Fusion::pause_escrow(RuntimeOrigin::signed(ALICE), 0u64), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(Fusion::pause_escrow(RuntimeOrigin::signed(PAUSE_GUARDIAN), 0u64));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Paused);
+        assert_eq!(last_event(), RuntimeEvent::Fusion(Event::EscrowPaused { escrow_id: 0 }));
+        // Still counted as locked while paused
+        assert_eq!(Fusion::total_active(), 1);
+
+        assert_noop!(
+            Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)),
+            Error::<Test>::OperationNotAllowed
+        );
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"paused")),
+            Error::<Test>::OperationNotAllowed
+        );
+
+        assert_ok!(Fusion::resume_escrow(RuntimeOrigin::root(), 0u64));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Active);
+        assert_noop!(Fusion::resume_escrow(RuntimeOrigin::root(), 0u64), Error::<Test>::InvalidEscrowState);
+
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Fusion::total_active(), 0);
+    });
+}
+
+#[test]
+fn only_active_escrows_can_be_paused() {
+    new_test_ext().execute_with(|| {
+        create_native_escrow(b"paused_hash", 1000);
+        assert_noop!(Fusion::pause_escrow(RuntimeOrigin::root(), 0u64), Error::<Test>::InvalidEscrowState);
+    });
+}
+
+// ===== Self-dealing filter tests =====
+
+#[test]
+fn related_pair_rejected_as_self_dealing() {
+    new_test_ext().execute_with(|| {
+        RelatedPairs::set(vec![(ALICE, BOB)]);
+
+        assert_noop!(
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                b"self_deal_hash".to_vec(),
+                100u64,
+                b"self deal".to_vec(),
+                None,
+            ),
+            Error::<Test>::SelfDealingDetected
+        );
+
+        // Unrelated pairs are unaffected, and can't be steered onto a flagged beneficiary
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            CHARLIE,
+            AssetInfo::Native,
+            1000u128,
+            b"self_deal_hash".to_vec(),
+            100u64,
+            b"self deal".to_vec(),
+            None,
+        ));
+        assert_noop!(
+            Fusion::reassign_beneficiary(RuntimeOrigin::signed(ALICE), 0u64, BOB),
+            Error::<Test>::SelfDealingDetected
+        );
+    });
+}
+
+// ===== Vesting payout tests =====
+
+fn create_vesting_escrow(hashlock: Vec<u8>, vesting: (u64, u64)) -> sp_runtime::DispatchResult {
+    Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        10_000u128,
+        hashlock,
+        100u64,
+        b"vesting".to_vec(),
+        None,
+        EscrowOptions { vesting: Some(vesting), ..Default::default() },
+    )
+}
+
+#[test]
+fn vesting_payout_released_linearly() {
+    new_test_ext().execute_with(|| {
+        let secret = b"vesting_secret";
+        assert_ok!(create_vesting_escrow(sp_core::hashing::sha2_256(secret).to_vec(), (10, 20)));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let bob_before = Balances::free_balance(BOB);
+
+        // Completion leaves the payout in the escrow account
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(secret)));
+        assert_eq!(Balances::free_balance(BOB), bob_before);
+        assert_eq!(Balances::free_balance(Fusion::escrow_account(&0u64)), 10_000);
+        assert_noop!(Fusion::claim_vested(RuntimeOrigin::signed(BOB), 0u64), Error::<Test>::NothingToClaim);
+
+        // Halfway through only half is claimable, and none of it is surplus
+        run_to_block(15);
+        assert_noop!(Fusion::sweep_surplus(RuntimeOrigin::signed(ALICE), 0u64), Error::<Test>::NoSurplus);
+        assert_ok!(Fusion::claim_vested(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Balances::free_balance(BOB), bob_before + 5_000);
+        assert_eq!(Fusion::vesting_schedules(0u64).unwrap().claimed, 5_000);
+        assert_noop!(Fusion::claim_vested(RuntimeOrigin::signed(BOB), 0u64), Error::<Test>::NothingToClaim);
+
+        // The rest is available once vesting ends
+        run_to_block(25);
+        assert_ok!(Fusion::claim_vested(RuntimeOrigin::signed(BOB), 0u64));
+        assert_eq!(Balances::free_balance(BOB), bob_before + 10_000);
+        assert_eq!(Fusion::vesting_schedules(0u64), None);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::Fusion(Event::VestedClaimed { escrow_id: 0, recipient: BOB, amount: 5_000 })
+        );
+    });
+}
+
+#[test]
+fn vesting_must_end_after_start() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            create_vesting_escrow(b"vesting_hash".to_vec(), (20, 20)),
+            Error::<Test>::InvalidVestingSchedule
+        );
+    });
+}
+
+// ===== Funding check tests =====
+
+#[test]
+fn drained_active_escrow_not_fully_funded() {
+    new_test_ext().execute_with(|| {
+        create_native_escrow(b"funded_hash", 1000);
+        assert!(!Fusion::is_fully_funded(&0u64));
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert!(Fusion::is_fully_funded(&0u64));
+
+        // Drain the escrow account behind the pallet's back
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), Fusion::escrow_account(&0u64), 600));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Active);
+        assert!(!Fusion::is_fully_funded(&0u64));
+        assert!(!Fusion::is_fully_funded(&1u64));
+    });
+}
+
+// ===== Relayer reward tests =====
+
+fn create_rewarded_escrow(hashlock: Vec<u8>, reward: u128) -> sp_runtime::DispatchResult {
+    Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        hashlock,
+        100u64,
+        b"reward".to_vec(),
+        None,
+        EscrowOptions { relayer_reward: Some(reward), ..Default::default() },
+    )
+}
+
+#[test]
+fn funding_covers_principal_and_relayer_reward() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create_rewarded_escrow(b"reward_hash".to_vec(), 50));
+        let alice_before = Balances::free_balance(ALICE);
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Balances::free_balance(ALICE), alice_before - 1050);
+        assert_eq!(Balances::free_balance(Fusion::escrow_account(&0u64)), 1050);
+        assert!(Fusion::is_fully_funded(&0u64));
+
+        // Cancelling returns the reward along with the principal
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"no swap")));
+        assert_eq!(Balances::free_balance(ALICE), alice_before);
+    });
+}
+
+#[test]
+fn third_party_relayer_receives_reward_on_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = bounded_secret(b"reward_secret");
+        assert_ok!(create_rewarded_escrow(sp_core::hashing::sha2_256(&secret).to_vec(), 50));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let (bob_before, charlie_before) = (Balances::free_balance(BOB), Balances::free_balance(CHARLIE));
+
+        let sig = completion_auth(ALICE, 0, &secret);
+        assert_ok!(Fusion::complete_escrow_with_auth(RuntimeOrigin::signed(CHARLIE), 0u64, secret, sig));
+
+        assert_eq!(Balances::free_balance(BOB), bob_before + 1000);
+        assert_eq!(Balances::free_balance(CHARLIE), charlie_before + 50);
+        let expected = RuntimeEvent::Fusion(Event::RelayerRewardPaid { escrow_id: 0, relayer: CHARLIE, amount: 50 });
+        assert!(System::events().iter().any(|record| record.event == expected));
+    });
+}
+
+#[test]
+fn zero_relayer_reward_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(create_rewarded_escrow(b"reward_hash".to_vec(), 0), Error::<Test>::InvalidRelayerReward);
+    });
+}
+
+// ===== Asset registration tests =====
+
+#[test]
+fn unregistered_asset_rejected_at_creation() {
+    new_test_ext().execute_with(|| {
+        let create = |asset_id: u32| {
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Asset(asset_id),
+                1000u128,
+                b"registered_hash".to_vec(),
+                100u64,
+                b"registered".to_vec(),
+                None,
+            )
+        };
+        assert_noop!(create(42), Error::<Test>::InvalidAsset);
+
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 42u32.into(), ALICE, true, 1));
+        assert_ok!(create(42));
+    });
+}
+
+#[test]
+fn unregistered_stablecoin_rejected_at_creation() {
+    new_test_ext().execute_with(|| {
+        let create = |asset_id: u32| {
+            Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Stablecoin { asset_id, decimals: 6, symbol: b"USDC".to_vec().try_into().unwrap() },
+                1000u128,
+                b"registered_hash".to_vec(),
+                100u64,
+                b"registered".to_vec(),
+                None,
+            )
+        };
+        assert_noop!(create(42), Error::<Test>::InvalidAsset);
+
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), 42u32.into(), ALICE, true, 1));
+        assert_ok!(create(42));
+    });
+}
+
+// ===== XCM destination encoding tests =====
+
+#[test]
+fn xcm_destination_round_trips_through_storage() {
+    new_test_ext().execute_with(|| {
+        let destination = MultiLocation::new(
+            1,
+            Junctions::X2(Junction::Parachain(2000), Junction::AccountId32 { network: None, id: [9u8; 32] }),
+        );
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"round_trip_hash".to_vec(),
+            100u64,
+            b"round trip".to_vec(),
+            Some(xcm_route_to(destination)),
+        ));
+
+        let stored = Fusion::escrows(0u64).unwrap().xcm_route.unwrap();
+        let decoded = <VersionedMultiLocation as codec::Decode>::decode(&mut &stored.destination[..]).unwrap();
+        assert_eq!(MultiLocation::try_from(decoded), Ok(destination));
+    });
+}
+
+// ===== Ethereum order hash tests =====
+
+#[test]
+fn eth_order_hash_echoed_on_completion() {
+    new_test_ext().execute_with(|| {
+        let secret = b"order_hash_secret".to_vec();
+        let order_hash = [0xabu8; 32];
+        assert_ok!(Fusion::create_escrow_with_options(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            sp_core::hashing::sha2_256(&secret).to_vec(),
+            100u64,
+            b"order hash".to_vec(),
+            None,
+            EscrowOptions { eth_order_hash: Some(order_hash), ..Default::default() },
+        ));
+        assert_eq!(Fusion::escrows(0u64).unwrap().options.eth_order_hash, Some(order_hash));
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(&secret)));
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Fusion(Event::EscrowCompleted { escrow_id: 0, eth_order_hash: Some(hash), .. })
+                if hash == order_hash
+        )));
+
+        // The claim payload carries the linked order hash rather than the hashlock
+        System::assert_last_event(RuntimeEvent::Fusion(Event::EthClaimPayload {
+            escrow_id: 0,
+            payload: Fusion::eth_claim_payload(&secret, &order_hash).unwrap(),
+        }));
+    });
+}
+
+// ===== DOT TVL tests =====
+
+#[test]
+fn tvl_in_dot_tracks_converted_asset_value() {
+    new_test_ext().execute_with(|| {
+        let asset_id = 12u32;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id.into(), ALICE, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), asset_id.into(), ALICE, 10_000));
+        let secret = b"dot_tvl_secret".to_vec();
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Asset(asset_id),
+            1000u128,
+            sp_core::hashing::sha2_256(&secret).to_vec(),
+            100u64,
+            b"dot tvl".to_vec(),
+            None,
+        ));
+        assert_eq!(Fusion::tvl_in_dot(), 0);
+
+        // The mock oracle prices the asset at two DOT per unit
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_eq!(Fusion::tvl_in_dot(), 2000);
+
+        // The recorded value comes off even if the rate has since moved
+        OracleRate::set(Some(3));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 0u64, bounded_secret(&secret)));
+        assert_eq!(Fusion::tvl_in_dot(), 0);
+    });
+}
+
+#[test]
+fn tvl_in_dot_skips_unpriced_escrows() {
+    new_test_ext().execute_with(|| {
+        let asset_id = 12u32;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id.into(), ALICE, true, 1));
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), asset_id.into(), ALICE, 10_000));
+        OracleRate::set(None);
+        for (asset, hashlock) in [(AssetInfo::Asset(asset_id), b"unpriced"), (AssetInfo::Native, b"native__")] {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                asset,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"dot tvl".to_vec(),
+                None,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+
+        // Native counts at face value; the unpriced asset is left out
+        assert_eq!(Fusion::tvl_in_dot(), 1000);
+
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 1u64, cancel_reason(b"done")));
+        assert_eq!(Fusion::tvl_in_dot(), 0);
+    });
+}
+
+// ===== Renewal tests =====
+
+#[test]
+fn renew_escrow_copies_terms_with_fresh_hashlock_and_timelock() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            AssetInfo::Native,
+            1000u128,
+            b"old_hashlock".to_vec(),
+            100u64,
+            b"rolling swap".to_vec(),
+            None,
+        ));
+        assert_noop!(
+            Fusion::renew_escrow(RuntimeOrigin::signed(ALICE), 0u64, b"new_hashlock".to_vec(), 200u64),
+            Error::<Test>::InvalidEscrowState
+        );
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"expired")));
+
+        assert_noop!(
+            Fusion::renew_escrow(RuntimeOrigin::signed(BOB), 0u64, b"new_hashlock".to_vec(), 200u64),
+            Error::<Test>::NotCreator
+        );
+
+        System::set_block_number(10);
+        assert_ok!(Fusion::renew_escrow(RuntimeOrigin::signed(ALICE), 0u64, b"new_hashlock".to_vec(), 200u64));
+        System::assert_last_event(RuntimeEvent::Fusion(Event::EscrowRenewed { previous_escrow_id: 0, escrow_id: 1 }));
+
+        let old = Fusion::escrows(0u64).unwrap();
+        let renewed = Fusion::escrows(1u64).unwrap();
+        assert_eq!(renewed.state, EscrowState::Created);
+        assert_eq!(renewed.creator, old.creator);
+        assert_eq!(renewed.beneficiary, old.beneficiary);
+        assert_eq!(renewed.asset, old.asset);
+        assert_eq!(renewed.amount, old.amount);
+        assert_eq!(renewed.metadata, old.metadata);
+        assert_eq!(renewed.hashlock.to_vec(), b"new_hashlock".to_vec());
+        assert_eq!(renewed.timelock, 210);
+        assert_ne!(renewed.timelock, old.timelock);
+    });
+}
+
+// ===== XCM completion weight tests =====
+
+#[test]
+fn xcm_completion_is_charged_more_weight() {
+    new_test_ext().execute_with(|| {
+        let secrets: [&[u8]; 2] = [b"local_weight", b"xcm___weight"];
+        for (secret, route) in secrets.into_iter().zip([None, Some(parachain_route(2000))]) {
+            assert_ok!(Fusion::create_escrow(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                sp_core::hashing::sha2_256(secret).to_vec(),
+                100u64,
+                b"weight".to_vec(),
+                route,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+
+        // Declared up front without reading the escrow, so both pay the XCM worst case
+        let call = |escrow_id: u64| {
+            let secret = bounded_secret(secrets[escrow_id as usize]);
+            RuntimeCall::Fusion(crate::Call::complete_escrow { escrow_id, secret })
+        };
+        let declared = call(1).get_dispatch_info().weight;
+        assert_eq!(call(0).get_dispatch_info().weight, declared);
+        assert!(declared.all_gte(XcmWeightLimit::get()));
+
+        // A local completion is refunded down to the local weight; a routed one keeps the full charge
+        let local = call(0).dispatch(RuntimeOrigin::signed(BOB)).unwrap();
+        let local_weight = <() as crate::WeightInfo>::complete_escrow(secrets[0].len() as u32);
+        assert_eq!(local.actual_weight, Some(local_weight));
+        assert!(declared.all_gt(local_weight));
+        let routed = call(1).dispatch(RuntimeOrigin::signed(BOB)).unwrap();
+        assert_eq!(routed.actual_weight, None);
+        assert_eq!(XcmExecutedCount::get(), 1);
+    });
+}
+
+#[test]
+fn every_completion_call_declares_the_xcm_worst_case() {
+    new_test_ext().execute_with(|| {
+        let secret = bounded_secret(b"worst_case");
+        let calls = [
+            crate::Call::complete_escrow_with_auth {
+                escrow_id: 0,
+                secret: secret.clone(),
+                creator_sig: TestSignature(ALICE, Vec::new()),
+            },
+            crate::Call::complete_escrow_to { escrow_id: 0, secret: secret.clone(), recipient: BOB, min_amount_out: 0 },
+            crate::Call::complete_escrow_batch { items: vec![(0, secret.clone())].try_into().unwrap() },
+            crate::Call::reveal_secret { secret: secret.clone() },
+        ];
+        for call in calls {
+            assert!(RuntimeCall::Fusion(call).get_dispatch_info().weight.all_gte(XcmWeightLimit::get()));
+        }
+
+        // Local completions are refunded down to the call's own weight
+        create_native_escrow(&sp_core::hashing::sha2_256(b"worst_case"), 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let call = crate::Call::complete_escrow_batch { items: vec![(0, secret)].try_into().unwrap() };
+        let post = RuntimeCall::Fusion(call).dispatch(RuntimeOrigin::signed(BOB)).unwrap();
+        assert_eq!(post.actual_weight, Some(<() as crate::WeightInfo>::complete_escrow_batch(1)));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Completed);
+    });
+}
+
+// ===== Cancel reason bound tests =====
+
+#[test]
+fn cancel_reason_is_bounded() {
+    new_test_ext().execute_with(|| {
+        let encoded_call = |reason: Vec<u8>| {
+            let call = crate::Call::cancel_escrow { escrow_id: 0, reason: Default::default() };
+            let mut encoded = RuntimeCall::Fusion(call).encode();
+            // Swap the empty reason for a raw one of any length
+            encoded.pop();
+            encoded.extend(reason.encode());
+            encoded
+        };
+        let max_len = MAX_CANCEL_REASON_LEN as usize;
+        assert!(RuntimeCall::decode(&mut &encoded_call(vec![b'x'; max_len + 1])[..]).is_err());
+
+        create_native_escrow(b"reason_bound", 1000);
+        let call = RuntimeCall::decode(&mut &encoded_call(vec![b'x'; max_len])[..]).unwrap();
+        assert_ok!(call.dispatch(RuntimeOrigin::signed(ALICE)));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+// ===== Threshold maker tests =====
+
+fn create_threshold_escrow(hashlock: &[u8], threshold: u16, signers: Vec<u64>) -> sp_runtime::DispatchResult {
+    Fusion::create_escrow_with_options(
+        RuntimeOrigin::signed(ALICE),
+        BOB,
+        AssetInfo::Native,
+        1000u128,
+        hashlock.to_vec(),
+        100u64,
+        b"institutional".to_vec(),
+        None,
+        EscrowOptions { maker_threshold: Some((threshold, signers.try_into().unwrap())), ..Default::default() },
+    )
+}
+
+#[test]
+fn threshold_maker_cancels_once_signers_approve() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create_threshold_escrow(b"threshold_hash", 2, vec![CHARLIE, DAVE, 5]));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let alice_before = Balances::free_balance(ALICE);
+
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"alone")),
+            Error::<Test>::MakerApprovalRequired
+        );
+        assert_noop!(Fusion::approve_cancel(RuntimeOrigin::signed(BOB), 0u64), Error::<Test>::NotMakerSigner);
+
+        assert_ok!(Fusion::approve_cancel(RuntimeOrigin::signed(CHARLIE), 0u64));
+        System::assert_last_event(RuntimeEvent::Fusion(Event::CancelApproved {
+            escrow_id: 0,
+            signer: CHARLIE,
+            approvals: 1,
+        }));
+        assert_noop!(Fusion::approve_cancel(RuntimeOrigin::signed(CHARLIE), 0u64), Error::<Test>::AlreadyApproved);
+
+        assert_ok!(Fusion::approve_cancel(RuntimeOrigin::signed(DAVE), 0u64));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Cancelled);
+        assert_eq!(Balances::free_balance(ALICE), alice_before + 1000);
+        assert!(Fusion::cancel_approvals(0u64).is_empty());
+    });
+}
+
+#[test]
+fn threshold_maker_below_threshold_stays_active() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            create_threshold_escrow(b"bad_threshold", 3, vec![CHARLIE, DAVE]),
+            Error::<Test>::InvalidMakerThreshold
+        );
+        assert_noop!(
+            create_threshold_escrow(b"bad_threshold", 0, vec![CHARLIE, DAVE]),
+            Error::<Test>::InvalidMakerThreshold
+        );
+        assert_noop!(
+            create_threshold_escrow(b"bad_threshold", 2, vec![CHARLIE, CHARLIE]),
+            Error::<Test>::InvalidMakerThreshold
+        );
+
+        assert_ok!(create_threshold_escrow(b"threshold_hash", 2, vec![CHARLIE, DAVE]));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::approve_cancel(RuntimeOrigin::signed(CHARLIE), 0u64));
+
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Active);
+        assert_eq!(Fusion::cancel_approvals(0u64).into_inner(), vec![CHARLIE]);
+    });
+}
+
+#[test]
+fn threshold_maker_cannot_bypass_signers_through_other_cancel_paths() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(create_threshold_escrow(b"threshold_hash", 2, vec![CHARLIE, DAVE]));
+        assert_noop!(
+            Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 0u64),
+            Error::<Test>::MakerApprovalRequired
+        );
+
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        let sig = TestSignature(ALICE, (MUTUAL_CANCEL_CONTEXT, 0u64).encode());
+        assert_noop!(
+            Fusion::mutual_cancel(RuntimeOrigin::signed(BOB), 0u64, sig),
+            Error::<Test>::MakerApprovalRequired
+        );
+
+        assert_ok!(Fusion::approve_cancel(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_ok!(Fusion::approve_cancel(RuntimeOrigin::signed(DAVE), 0u64));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+// ===== Fallback taker tests =====
+
+#[test]
+fn payout_goes_to_fallback_when_taker_cannot_receive() {
+    new_test_ext().execute_with(|| {
+        // DAVE's balance can't take another 1000 without overflowing
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), DAVE, u128::MAX - 500));
+        let options = EscrowOptions { fallback_taker: Some(BOB), ..Default::default() };
+        for (secret, options) in [(b"no_fallback", EscrowOptions::default()), (b"fallback___", options)] {
+            assert_ok!(Fusion::create_escrow_with_options(
+                RuntimeOrigin::signed(ALICE),
+                DAVE,
+                AssetInfo::Native,
+                1000u128,
+                sp_core::hashing::sha2_256(secret).to_vec(),
+                100u64,
+                b"fallback".to_vec(),
+                None,
+                options,
+            ));
+        }
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+
+        // Without a fallback the completion fails as before
+        assert!(Fusion::complete_escrow(RuntimeOrigin::signed(DAVE), 0u64, bounded_secret(b"no_fallback")).is_err());
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Active);
+
+        let bob_before = Balances::free_balance(BOB);
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(DAVE), 1u64, bounded_secret(b"fallback___")));
+        assert_eq!(Fusion::escrows(1u64).unwrap().state, EscrowState::Completed);
+        assert_eq!(Balances::free_balance(BOB), bob_before + 1000);
+        assert_eq!(Balances::free_balance(DAVE), u128::MAX - 500);
+        System::assert_has_event(RuntimeEvent::Fusion(Event::PaidToFallback {
+            escrow_id: 1,
+            primary: DAVE,
+            fallback: BOB,
+            amount: 1000,
+        }));
+    });
+}
+
+// ===== NFT amount tests =====
+
+#[test]
+fn nft_escrow_amount_must_be_one() {
+    new_test_ext().execute_with(|| {
+        let nft = AssetInfo::Nft { collection_id: 1u32, item_id: 9, metadata: Default::default() };
+        for amount in [0u128, 5] {
+            assert_noop!(
+                Fusion::create_escrow(
+                    RuntimeOrigin::signed(ALICE),
+                    BOB,
+                    nft.clone(),
+                    amount,
+                    b"nft_amount".to_vec(),
+                    100u64,
+                    b"nft".to_vec(),
+                    None,
+                ),
+                Error::<Test>::InvalidAsset
+            );
+        }
+        assert_ok!(Fusion::create_escrow(
+            RuntimeOrigin::signed(ALICE),
+            BOB,
+            nft,
+            1u128,
+            b"nft_amount".to_vec(),
+            100u64,
+            b"nft".to_vec(),
+            None,
+        ));
+    });
+}
+
+// ===== Hashlock availability tests =====
+
+#[test]
+fn hashlock_is_reusable_after_any_settlement() {
+    new_test_ext().execute_with(|| {
+        let secret = b"reusable_secret".to_vec();
+        let hashlock = sp_core::hashing::sha2_256(&secret);
+        assert!(Fusion::is_secret_hash_available(&hashlock));
+
+        // Cancelled before funding
+        create_native_escrow(&hashlock, 1000);
+        assert!(!Fusion::is_secret_hash_available(&hashlock));
+        assert_ok!(Fusion::cancel_before_funding(RuntimeOrigin::signed(ALICE), 0u64));
+        assert!(Fusion::is_secret_hash_available(&hashlock));
+
+        // Cancelled after expiry
+        create_native_escrow(&hashlock, 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+        run_to_block(200);
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 1u64, cancel_reason(b"expired")));
+        assert!(Fusion::is_secret_hash_available(&hashlock));
+
+        // Completed
+        create_native_escrow(&hashlock, 1000);
+        assert_eq!(Fusion::escrows(2u64).unwrap().state, EscrowState::Created);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 2u64));
+        assert_ok!(Fusion::complete_escrow(RuntimeOrigin::signed(BOB), 2u64, bounded_secret(&secret)));
+        assert!(Fusion::is_secret_hash_available(&hashlock));
+    });
+}
+
+// ===== Creation nonce tests =====
+
+#[test]
+fn reused_nonce_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let create = |hashlock: &[u8], nonce: u64| {
+            Fusion::create_escrow_with_options(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                AssetInfo::Native,
+                1000u128,
+                hashlock.to_vec(),
+                100u64,
+                b"nonce".to_vec(),
+                None,
+                EscrowOptions { nonce: Some(nonce), ..Default::default() },
+            )
+        };
+        assert_ok!(create(b"first_hashlock", 7));
+
+        // A retry with a regenerated hashlock is caught by the nonce
+        assert_noop!(create(b"other_hashlock", 7), Error::<Test>::NonceAlreadyUsed);
+        assert_ok!(create(b"other_hashlock", 8));
+        assert_eq!(Fusion::next_escrow_id(), 2);
+    });
+}
+
+// ===== Counterparty reveal tests =====
+
+#[test]
+fn reveal_on_counterparty_chain_blocks_cancel() {
+    new_test_ext().execute_with(|| {
+        create_native_escrow(b"revealed_hashlock", 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        run_to_block(200);
+
+        RevealedEscrows::set(vec![0]);
+        assert_noop!(
+            Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"expired")),
+            Error::<Test>::RevealedElsewhere
+        );
+
+        // Without a reported reveal the refund goes through
+        RevealedEscrows::set(vec![]);
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"expired")));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+#[test]
+fn reveal_on_counterparty_chain_blocks_third_party_refund() {
+    new_test_ext().execute_with(|| {
+        create_native_escrow(b"revealed_claim", 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        run_to_block(200);
+
+        RevealedEscrows::set(vec![0]);
+        assert_noop!(
+            Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64),
+            Error::<Test>::RevealedElsewhere
+        );
+
+        RevealedEscrows::set(vec![]);
+        assert_ok!(Fusion::claim_expired_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
+
+#[test]
+fn sweep_skips_escrows_revealed_on_counterparty_chain() {
+    new_test_ext().execute_with(|| {
+        let alice_initial = Balances::free_balance(ALICE);
+        create_native_escrow(b"revealed_sweep_0", 1000);
+        create_native_escrow(b"revealed_sweep_1", 1000);
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 0u64));
+        assert_ok!(Fusion::fund_escrow(RuntimeOrigin::signed(ALICE), 1u64));
+        run_to_block(200);
+
+        RevealedEscrows::set(vec![1]);
+        assert_ok!(Fusion::sweep_expired(RuntimeOrigin::signed(CHARLIE), vec![0, 1].try_into().unwrap()));
+
+        System::assert_last_event(RuntimeEvent::Fusion(Event::ExpiredEscrowsSwept {
+            refunded: vec![0],
+            skipped: vec![1],
+        }));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Cancelled);
+        assert_eq!(Fusion::escrows(1u64).unwrap().state, EscrowState::Active);
+        assert_eq!(Balances::free_balance(ALICE), alice_initial - 1000);
+    });
+}
+
+#[test]
+fn reveal_on_counterparty_chain_blocks_finalized_refund() {
+    new_test_ext().execute_with(|| {
+        create_disputable_escrow();
+        run_to_block(106);
+        assert_ok!(Fusion::cancel_escrow(RuntimeOrigin::signed(ALICE), 0u64, cancel_reason(b"expired")));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Disputable);
+
+        // Revealed while the dispute window was open
+        run_to_block(126);
+        RevealedEscrows::set(vec![0]);
+        assert_noop!(Fusion::finalize_refund(RuntimeOrigin::signed(CHARLIE), 0u64), Error::<Test>::RevealedElsewhere);
+
+        RevealedEscrows::set(vec![]);
+        assert_ok!(Fusion::finalize_refund(RuntimeOrigin::signed(CHARLIE), 0u64));
+        assert_eq!(Fusion::escrows(0u64).unwrap().state, EscrowState::Cancelled);
+    });
+}
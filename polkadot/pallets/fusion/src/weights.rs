@@ -14,12 +14,53 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions for `pallet_fusion`.
 pub trait WeightInfo {
-    fn create_escrow() -> Weight;
+    fn create_escrow(m: u32) -> Weight;
     fn fund_escrow() -> Weight;
-    fn complete_escrow() -> Weight;
+    fn complete_escrow(s: u32) -> Weight;
+    fn complete_escrow_xcm(s: u32) -> Weight;
     fn cancel_escrow() -> Weight;
     fn emergency_pause() -> Weight;
     fn emergency_unpause() -> Weight;
+    fn claim_expired_refund() -> Weight;
+    fn cancel_before_funding() -> Weight;
+    fn top_up_escrow() -> Weight;
+    fn create_escrow_with_id(m: u32) -> Weight;
+    fn reassign_beneficiary() -> Weight;
+    fn set_pause_flags() -> Weight;
+    fn complete_escrow_with_auth() -> Weight;
+    fn create_escrow_with_options(m: u32) -> Weight;
+    fn post_bond() -> Weight;
+    fn update_metadata(m: u32) -> Weight;
+    fn freeze_escrow() -> Weight;
+    fn unfreeze_escrow() -> Weight;
+    fn reveal_secret(n: u32) -> Weight;
+    fn sweep_expired(n: u32) -> Weight;
+    fn complete_escrow_to() -> Weight;
+    fn set_allowed_asset() -> Weight;
+    fn complete_escrow_batch(n: u32) -> Weight;
+    fn rotate_secret_hash() -> Weight;
+    fn mutual_cancel() -> Weight;
+    fn verify_solvency(n: u32) -> Weight;
+    fn finalize_refund() -> Weight;
+    fn raise_dispute() -> Weight;
+    fn withdraw_fees() -> Weight;
+    fn register_template(m: u32) -> Weight;
+    fn create_from_template(m: u32) -> Weight;
+    fn emergency_withdraw() -> Weight;
+    fn set_secret_retention() -> Weight;
+    fn set_asset_timelock_bounds() -> Weight;
+    fn transfer_escrow_ownership() -> Weight;
+    fn prune_escrow() -> Weight;
+    fn accept_escrow() -> Weight;
+    fn sweep_surplus() -> Weight;
+    fn notify_expiry() -> Weight;
+    fn set_funding_approval() -> Weight;
+    fn fund_escrow_on_behalf() -> Weight;
+    fn pause_escrow() -> Weight;
+    fn resume_escrow() -> Weight;
+    fn claim_vested() -> Weight;
+    fn renew_escrow(m: u32) -> Weight;
+    fn approve_cancel() -> Weight;
 }
 
 /// Weights for pallet_fusion using the Substrate reference hardware.
@@ -34,8 +75,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Proof: FusionEscrow AccountEscrows (max_values: None, max_size: Some(8004), added: 10479, mode: MaxEncodedLen)
     /// Proof: FusionEscrow EscrowStats (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
     /// Storage: FusionEscrow EscrowsByTaker (r:1 w:1)
-    fn create_escrow() -> Weight {
+    /// The range of component `m` is `[0, 1024]`.
+    fn create_escrow(m: u32) -> Weight {
         Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
             .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(5))
     }
@@ -52,37 +95,405 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Storage: FusionEscrow Escrows (r:1 w:1)
     /// Storage: Assets Account (r:2 w:2)
     /// Storage: System Account (r:1 w:1)
-    fn complete_escrow() -> Weight {
+    /// The range of component `s` is `[1, 64]`.
+    fn complete_escrow(s: u32) -> Weight {
         Weight::from_parts(45_000_000, 0)
+            .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(s.into()))
             .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(4))
     }
 
+    /// Storage: FusionEscrow Escrows (r:2 w:1)
+    /// Storage: System Account (r:2 w:2)
+    /// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+    /// The range of component `s` is `[1, 64]`.
+    fn complete_escrow_xcm(s: u32) -> Weight {
+        Weight::from_parts(95_000_000, 0)
+            .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
     fn cancel_escrow() -> Weight {
         Weight::from_parts(40_000_000, 0)
             .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().writes(4))
     }
 
-    /// Storage: FusionEscrow EmergencyPaused (r:0 w:1)
+    /// Storage: FusionEscrow Paused (r:0 w:1)
     fn emergency_pause() -> Weight {
         Weight::from_parts(25_000_000, 0)
             .saturating_add(T::DbWeight::get().writes(1))
     }
 
-    /// Storage: FusionEscrow EmergencyPaused (r:0 w:1)
+    /// Storage: FusionEscrow Paused (r:0 w:1)
     fn emergency_unpause() -> Weight {
         Weight::from_parts(25_000_000, 0)
             .saturating_add(T::DbWeight::get().writes(1))
     }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    fn claim_expired_refund() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:2)
+    /// Storage: FusionEscrow AccountEscrows (r:1 w:1)
+    fn cancel_before_funding() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    fn top_up_escrow() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow AccountEscrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:1)
+    /// Storage: FusionEscrow Paused (r:1 w:0)
+    /// The range of component `m` is `[0, 1024]`.
+    fn create_escrow_with_id(m: u32) -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow BeneficiaryEscrows (r:2 w:2)
+    fn reassign_beneficiary() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Paused (r:0 w:1)
+    fn set_pause_flags() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow Paused (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
+    fn complete_escrow_with_auth() -> Weight {
+        Weight::from_parts(90_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow NextEscrowId (r:1 w:1)
+    /// Storage: FusionEscrow AccountEscrows (r:1 w:1)
+    /// Storage: FusionEscrow BeneficiaryEscrows (r:1 w:1)
+    /// The range of component `m` is `[0, 1024]`.
+    fn create_escrow_with_options(m: u32) -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: System Account (r:1 w:1)
+    fn post_bond() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// The range of component `m` is `[0, 1024]`.
+    fn update_metadata(m: u32) -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    fn freeze_escrow() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    fn unfreeze_escrow() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow EscrowsByHashlock (r:1 w:n)
+    /// Storage: FusionEscrow Escrows (r:n w:n)
+    /// Storage: System Account (r:2n w:2n)
+    /// The range of component `n` is `[1, MaxLinkedReveals]`.
+    fn reveal_secret(n: u32) -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(80_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: FusionEscrow Paused (r:1 w:0)
+    /// Storage: FusionEscrow Escrows (r:n w:n)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:2n)
+    /// Storage: System Account (r:2n w:2n)
+    fn sweep_expired(n: u32) -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: Assets Account (r:2 w:2)
+    /// Storage: System Account (r:1 w:1)
+    fn complete_escrow_to() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Storage: FusionEscrow AllowedAssets (r:1 w:1)
+    /// Storage: FusionEscrow AllowedAssetCount (r:1 w:1)
+    fn set_allowed_asset() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: FusionEscrow Paused (r:1 w:0)
+    /// Storage: FusionEscrow Escrows (r:n w:n)
+    /// Storage: System Account (r:2n w:2n)
+    fn complete_escrow_batch(n: u32) -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByHashlock (r:1 w:2)
+    fn rotate_secret_hash() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    fn mutual_cancel() -> Weight {
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow EscrowsByState (r:n w:0)
+    /// Storage: FusionEscrow Escrows (r:n w:0)
+    /// Storage: System Account (r:n w:0)
+    fn verify_solvency(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(10_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow RefundHolds (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:2)
+    /// Storage: System Account (r:2 w:2)
+    fn finalize_refund() -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow RefundHolds (r:1 w:0)
+    fn raise_dispute() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow AccumulatedFees (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    fn withdraw_fees() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow NextTemplateId (r:1 w:1)
+    /// Storage: FusionEscrow EscrowTemplates (r:0 w:1)
+    /// The range of component `m` is `[0, 1024]`.
+    fn register_template(m: u32) -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: FusionEscrow EscrowTemplates (r:1 w:0)
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow NextEscrowId (r:1 w:1)
+    /// Storage: FusionEscrow AccountEscrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByHashlock (r:1 w:2)
+    /// The range of component `m` is `[0, 1024]`.
+    fn create_from_template(m: u32) -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow RefundHolds (r:0 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:2)
+    /// Storage: System Account (r:3 w:2)
+    fn emergency_withdraw() -> Weight {
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    /// Storage: FusionEscrow RetainRevealedSecrets (r:0 w:1)
+    fn set_secret_retention() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow AssetTimelockBounds (r:0 w:1)
+    fn set_asset_timelock_bounds() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow AccountEscrows (r:2 w:2)
+    fn transfer_escrow_ownership() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:1)
+    /// Storage: FusionEscrow EscrowSalts (r:0 w:1)
+    /// Storage: FusionEscrow RefundHolds (r:0 w:1)
+    /// Storage: FusionEscrow RevealedSecrets (r:0 w:1)
+    fn prune_escrow() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:2)
+    fn accept_escrow() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:0)
+    /// Storage: System Account (r:2 w:2)
+    fn sweep_surplus() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:0)
+    fn notify_expiry() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+    }
+
+    /// Storage: FusionEscrow FundingApprovals (r:0 w:1)
+    fn set_funding_approval() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Storage: FusionEscrow FundingApprovals (r:1 w:1)
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: Assets Account (r:2 w:2)
+    /// Storage: System Account (r:1 w:1)
+    fn fund_escrow_on_behalf() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:2)
+    fn pause_escrow() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByState (r:0 w:2)
+    fn resume_escrow() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow VestingSchedules (r:1 w:1)
+    /// Storage: FusionEscrow Escrows (r:1 w:0)
+    /// Storage: System Account (r:1 w:2)
+    fn claim_vested() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:2 w:1)
+    /// Storage: FusionEscrow NextEscrowId (r:1 w:1)
+    /// Storage: FusionEscrow AccountEscrows (r:1 w:1)
+    /// Storage: FusionEscrow EscrowsByHashlock (r:1 w:1)
+    /// The range of component `m` is `[0, 1024]`.
+    fn renew_escrow(m: u32) -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    /// Storage: FusionEscrow Escrows (r:1 w:1)
+    /// Storage: FusionEscrow CancelApprovals (r:1 w:1)
+    /// Storage: System Account (r:2 w:2)
+    fn approve_cancel() -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-    fn create_escrow() -> Weight {
+    fn create_escrow(m: u32) -> Weight {
         Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
             .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
     }
 
     fn fund_escrow() -> Weight {
@@ -91,12 +502,20 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(3))
     }
 
-    fn complete_escrow() -> Weight {
+    fn complete_escrow(s: u32) -> Weight {
         Weight::from_parts(85_000_000, 0)
+            .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(s.into()))
             .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(3))
     }
 
+    fn complete_escrow_xcm(s: u32) -> Weight {
+        Weight::from_parts(135_000_000, 0)
+            .saturating_add(Weight::from_parts(20_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
     fn cancel_escrow() -> Weight {
         Weight::from_parts(70_000_000, 0)
             .saturating_add(RocksDbWeight::get().reads(4))
@@ -112,4 +531,252 @@ impl WeightInfo for () {
         Weight::from_parts(25_000_000, 0)
             .saturating_add(RocksDbWeight::get().writes(1))
     }
+
+    fn claim_expired_refund() -> Weight {
+        Weight::from_parts(70_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn cancel_before_funding() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn top_up_escrow() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn create_escrow_with_id(m: u32) -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn reassign_beneficiary() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn set_pause_flags() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn complete_escrow_with_auth() -> Weight {
+        Weight::from_parts(110_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn create_escrow_with_options(m: u32) -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn post_bond() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn update_metadata(m: u32) -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn freeze_escrow() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn unfreeze_escrow() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn reveal_secret(n: u32) -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(Weight::from_parts(80_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+    }
+
+    fn sweep_expired(n: u32) -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(40_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+    }
+
+    fn complete_escrow_to() -> Weight {
+        Weight::from_parts(85_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn set_allowed_asset() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn complete_escrow_batch(n: u32) -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(85_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+    }
+
+    fn rotate_secret_hash() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn mutual_cancel() -> Weight {
+        Weight::from_parts(90_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn verify_solvency(n: u32) -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+    }
+
+    fn finalize_refund() -> Weight {
+        Weight::from_parts(80_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+
+    fn raise_dispute() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn withdraw_fees() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn register_template(m: u32) -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn create_from_template(m: u32) -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+
+    fn emergency_withdraw() -> Weight {
+        Weight::from_parts(90_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+
+    fn set_secret_retention() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn set_asset_timelock_bounds() -> Weight {
+        Weight::from_parts(18_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn transfer_escrow_ownership() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn prune_escrow() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+
+    fn accept_escrow() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn sweep_surplus() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn notify_expiry() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+    }
+
+    fn set_funding_approval() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn fund_escrow_on_behalf() -> Weight {
+        Weight::from_parts(80_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+
+    fn pause_escrow() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn resume_escrow() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn claim_vested() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn renew_escrow(m: u32) -> Weight {
+        Weight::from_parts(55_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+
+    fn approve_cancel() -> Weight {
+        Weight::from_parts(85_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
 }
@@ -340,6 +340,9 @@ pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (pallet_fusion::migrations::InitializeStorageVersion<Runtime>,);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -347,6 +350,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
 /// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
@@ -368,6 +372,21 @@ pub type Hash = sp_core::H256;
 /// An index to a block.
 pub type BlockNumber = u32;
 
+#[cfg(feature = "runtime-benchmarks")]
+#[macro_use]
+extern crate frame_benchmarking;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+    define_benchmarks!(
+        [frame_benchmarking, BaselineBench::<Runtime>]
+        [frame_system, SystemBench::<Runtime>]
+        [pallet_balances, Balances]
+        [pallet_timestamp, Timestamp]
+        [pallet_fusion, Fusion]
+    );
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -493,6 +512,53 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_fusion_runtime_api::FusionApi<
+        Block,
+        u32,
+        Balance,
+        <Runtime as pallet_fusion::Config>::EscrowId,
+        pallet_fusion::Escrow<Runtime>,
+        AccountId,
+    > for Runtime {
+        fn estimate_create_fee(asset: pallet_fusion::AssetInfo<u32>, amount: Balance) -> Balance {
+            Fusion::estimate_create_fee(&asset, amount)
+        }
+
+        fn escrows_paginated(
+            start_after: Option<<Runtime as pallet_fusion::Config>::EscrowId>,
+            limit: u32,
+        ) -> (
+            Vec<(<Runtime as pallet_fusion::Config>::EscrowId, pallet_fusion::Escrow<Runtime>)>,
+            Option<<Runtime as pallet_fusion::Config>::EscrowId>,
+        ) {
+            Fusion::escrows_paginated(start_after, limit)
+        }
+
+        fn completable_escrows_for_taker(taker: AccountId) -> Vec<<Runtime as pallet_fusion::Config>::EscrowId> {
+            Fusion::completable_escrows_for_taker(&taker)
+        }
+
+        fn escrow_account_of(escrow_id: <Runtime as pallet_fusion::Config>::EscrowId) -> AccountId {
+            Fusion::escrow_account(&escrow_id)
+        }
+
+        fn account_escrows_paginated(
+            creator: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<<Runtime as pallet_fusion::Config>::EscrowId> {
+            Fusion::get_account_escrows_paginated(&creator, offset, limit)
+        }
+
+        fn pallet_summary() -> pallet_fusion::PalletSummary<<Runtime as pallet_fusion::Config>::EscrowId> {
+            Fusion::pallet_summary()
+        }
+
+        fn is_fully_funded(escrow_id: <Runtime as pallet_fusion::Config>::EscrowId) -> bool {
+            Fusion::is_fully_funded(&escrow_id)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (